@@ -1,6 +1,21 @@
 extern crate autocfg;
 
 fn main() {
+    // Declares custom cfgs emitted below, so newer rustc versions will not warn about them.
+    for name in &[
+        "has_task_poll",
+        "has_private_in_public_issue",
+        "rustc_1_6",
+        "rustc_1_26",
+        "rustc_1_31",
+        "rustc_1_38",
+        "rustc_1_46",
+        "rustc_1_63",
+        "rustc_1_65",
+    ] {
+        println!("cargo:rustc-check-cfg=cfg({})", name);
+    }
+
     let cfg = autocfg::new();
     cfg.emit_path_cfg("core::task::Poll", "has_task_poll");
     cfg.emit_path_cfg("std::task::Poll", "has_task_poll");
@@ -8,9 +23,24 @@ fn main() {
     // Needed to enable `#![no_std]` only on rustc versions that support it (rustc 1.6.0 and up).
     cfg.emit_rustc_version(1, 6);
 
-    // Needed for `assert_matches!`' minimum rust version.
+    // Needed for `assert_matches!` minimum rust version.
     cfg.emit_rustc_version(1, 26);
 
+    // Needed for `assert_that!` and `claim::matchers` minimum rust version.
+    cfg.emit_rustc_version(1, 31);
+
+    // Needed for the type names in `assert_downcast!` failures.
+    cfg.emit_rustc_version(1, 38);
+
+    // Needed for `#[track_caller]` methods of `claim::fluent::Claim` trait.
+    cfg.emit_rustc_version(1, 46);
+
+    // Needed for the invariants sink, which is stored in the `RwLock` static.
+    cfg.emit_rustc_version(1, 63);
+
+    // Needed for the labeled blocks of `expect_*` and `ensure_*` macros.
+    cfg.emit_rustc_version(1, 65);
+
     if cfg.probe_rustc_version(1, 15) && !cfg.probe_rustc_version(1, 16) {
         autocfg::emit("has_private_in_public_issue");
     }
@@ -0,0 +1,583 @@
+//! Procedural macros for the [`claim`] crate.
+//!
+//! This crate is an implementation detail of the `claim` crate `power-assert` feature
+//! and should not be used directly.
+//!
+//! [`claim`]: https://docs.rs/claim
+
+#![deny(
+    rust_2018_idioms,
+    unused,
+    missing_debug_implementations,
+    missing_docs,
+    unsafe_code
+)]
+
+// Edition 2015 is kept for the older Cargo versions, which parse this manifest
+// as the path dependency of the `claim` crate.
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+use proc_macro2::{Delimiter, Ident, Spacing, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::{BinOp, Expr, ExprPath, Token, UnOp};
+
+/// Width of the `assertion failed: ` prefix, which is printed before the expression.
+const PREFIX_WIDTH: usize = 18;
+
+/// Arguments of the `claim!` macro: path to the `claim` crate, failure mode,
+/// expression and optional message.
+struct Input {
+    krate: TokenTree,
+    fail: TokenTree,
+    expr: Expr,
+    message: Option<TokenStream>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let krate = input.parse()?;
+        let _: Token![,] = input.parse()?;
+        let fail = input.parse()?;
+        let _: Token![;] = input.parse()?;
+        let expr = input.parse()?;
+        let mut message = None;
+        if !input.is_empty() {
+            let _: Token![,] = input.parse()?;
+            if !input.is_empty() {
+                message = Some(input.parse()?);
+            }
+        }
+        Ok(Input {
+            krate,
+            fail,
+            expr,
+            message,
+        })
+    }
+}
+
+/// Rewrites boolean expression to record its sub-expression values.
+///
+/// Invoked as `claim!($crate, $fail; expression, message...)` by the `claim!` macro of the `claim` crate,
+/// where `$fail` is passed to its `__fail!` macro as is.
+#[proc_macro]
+pub fn claim(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as Input);
+    expand(input).into()
+}
+
+fn expand(input: Input) -> TokenStream {
+    let mut generator = Generator {
+        krate: input.krate,
+        recorder: Ident::new("recorder", Span::mixed_site()),
+        source: String::new(),
+        records: 0,
+    };
+    let result = Ident::new("result", Span::mixed_site());
+    let body = generator.value(&input.expr);
+    let fail = input.fail;
+
+    let Generator {
+        krate,
+        recorder,
+        source,
+        records,
+        ..
+    } = generator;
+    let binding = if records > 0 {
+        quote!(mut #recorder)
+    } else {
+        quote!(#recorder)
+    };
+    let report = match input.message {
+        Some(message) => quote! {
+            #krate::__fail!(#fail, "claim", "assertion failed: {}{}: {}", #source, #recorder.diagram(#PREFIX_WIDTH), format_args!(#message))
+        },
+        None => quote! {
+            #krate::__fail!(#fail, "claim", "assertion failed: {}{}", #source, #recorder.diagram(#PREFIX_WIDTH))
+        },
+    };
+    quote! {{
+        let #binding = #krate::__private::Recorder::new();
+        let #result: bool = #body;
+        if !#result {
+            #report
+        }
+    }}
+}
+
+/// Expression rewriter, which also prints the expression source and remembers the columns
+/// of the recorded sub-expressions.
+struct Generator {
+    krate: TokenTree,
+    recorder: Ident,
+    source: String,
+    records: usize,
+}
+
+impl Generator {
+    fn column(&self) -> usize {
+        self.source.chars().count()
+    }
+
+    fn print(&mut self, text: &str) {
+        self.source.push_str(text);
+    }
+
+    /// Statement, which records the value behind the `reference` at the `column`.
+    fn record(&mut self, column: usize, reference: TokenStream) -> TokenStream {
+        self.records += 1;
+        let krate = &self.krate;
+        let recorder = &self.recorder;
+        quote! {
+            #recorder.record(#column, {
+                use #krate::__private::power_dispatch::*;
+                (&&&#krate::__private::Probe(#reference)).describe()
+            });
+        }
+    }
+
+    /// Records the evaluated value of the owned expression.
+    fn recorded(&mut self, column: usize, expr: TokenStream) -> TokenStream {
+        let value = Ident::new("value", Span::mixed_site());
+        let record = self.record(column, quote!(&#value));
+        quote! {
+            match #expr {
+                #value => {
+                    #record
+                    #value
+                }
+            }
+        }
+    }
+
+    /// Records the place behind the reference expression and returns that reference.
+    fn recorded_ref(&mut self, column: usize, reference: TokenStream) -> TokenStream {
+        let value = Ident::new("value", Span::mixed_site());
+        let record = self.record(column, quote!(#value));
+        quote! {
+            match #reference {
+                #value => {
+                    #record
+                    #value
+                }
+            }
+        }
+    }
+
+    /// Rewrites expression, which is evaluated by value.
+    fn value(&mut self, expr: &Expr) -> TokenStream {
+        match expr {
+            Expr::Lit(_) => {
+                self.print(&text(expr));
+                expr.to_token_stream()
+            }
+            _ if is_variable(expr) => {
+                let records = self.variable(expr);
+                quote!({ #records #expr })
+            }
+            Expr::Paren(paren) => {
+                self.print("(");
+                let inner = self.value(&paren.expr);
+                self.print(")");
+                quote!((#inner))
+            }
+            Expr::Binary(binary) => self.binary(binary),
+            Expr::Unary(unary) => match unary.op {
+                UnOp::Not(_) | UnOp::Neg(_) if !is_literal(&unary.expr) => {
+                    let column = self.column();
+                    self.print(&text(&unary.op));
+                    let operand = self.value(&unary.expr);
+                    let op = &unary.op;
+                    self.recorded(column, quote!(#op (#operand)))
+                }
+                UnOp::Deref(_) => {
+                    let reference = self.reference(expr);
+                    quote!((*#reference))
+                }
+                _ => self.leaf(expr),
+            },
+            Expr::Index(_) => {
+                let reference = self.reference(expr);
+                quote!((*#reference))
+            }
+            Expr::Field(field) if is_place(&field.base) => {
+                let reference = self.reference(expr);
+                quote!((*#reference))
+            }
+            Expr::Field(field) => {
+                let base = self.value(&field.base);
+                self.print(".");
+                self.print(&text(&field.member));
+                let member = &field.member;
+                quote!((#base).#member)
+            }
+            Expr::MethodCall(call) => {
+                let (records, receiver) = self.receiver(&call.receiver);
+                self.print(".");
+                let column = self.column();
+                self.print(&call.method.to_string());
+                if let Some(turbofish) = &call.turbofish {
+                    self.print(&text(turbofish));
+                }
+                let args = self.arguments(call.args.iter());
+                let method = &call.method;
+                let turbofish = &call.turbofish;
+                let call = self.recorded(column, quote!(#receiver.#method #turbofish(#(#args),*)));
+                quote!({ #records #call })
+            }
+            Expr::Call(call) => {
+                let column = self.column();
+                self.print(&text(&call.func));
+                let args = self.arguments(call.args.iter());
+                let func = &call.func;
+                self.recorded(column, quote!(#func(#(#args),*)))
+            }
+            Expr::Cast(cast) => {
+                let operand = self.value(&cast.expr);
+                self.print(" ");
+                let column = self.column();
+                self.print("as ");
+                self.print(&text(&cast.ty));
+                let ty = &cast.ty;
+                self.recorded(column, quote!((#operand) as #ty))
+            }
+            Expr::Reference(reference) if reference.mutability.is_none() => {
+                self.print("&");
+                self.reference(&reference.expr)
+            }
+            Expr::Macro(_) => {
+                let column = self.column();
+                self.print(&text(expr));
+                self.recorded(column, expr.to_token_stream())
+            }
+            _ => self.leaf(expr),
+        }
+    }
+
+    /// Rewrites expression, which is evaluated to a reference without moving the value.
+    fn reference(&mut self, expr: &Expr) -> TokenStream {
+        match expr {
+            _ if is_variable(expr) => {
+                let records = self.variable(expr);
+                quote!({ #records &#expr })
+            }
+            Expr::Paren(paren) => {
+                self.print("(");
+                let inner = self.reference(&paren.expr);
+                self.print(")");
+                inner
+            }
+            Expr::Index(index) => {
+                let (records, base) = self.receiver(&index.expr);
+                let column = self.column();
+                self.print("[");
+                let position = self.value(&index.index);
+                self.print("]");
+                let reference = self.recorded_ref(column, quote!(&#base[#position]));
+                quote!({ #records #reference })
+            }
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Deref(_)) => {
+                let column = self.column();
+                self.print("*");
+                let inner = self.reference(&unary.expr);
+                self.recorded_ref(column, quote!(&**#inner))
+            }
+            Expr::Field(field) if is_place(&field.base) => {
+                let (records, base) = self.receiver(&field.base);
+                self.print(".");
+                let column = self.column();
+                self.print(&text(&field.member));
+                let member = &field.member;
+                let reference = self.recorded_ref(column, quote!(&#base.#member));
+                quote!({ #records #reference })
+            }
+            _ => {
+                let value = self.value(expr);
+                quote!(&(#value))
+            }
+        }
+    }
+
+    /// Rewrites method receiver or index base, so they are not moved if they were not before.
+    ///
+    /// Returns statements, which should be executed before, and the receiver itself.
+    fn receiver(&mut self, expr: &Expr) -> (TokenStream, TokenStream) {
+        if is_variable(expr) {
+            (self.variable(expr), expr.to_token_stream())
+        } else if is_place(expr) {
+            let reference = self.reference(expr);
+            (TokenStream::new(), quote!((*#reference)))
+        } else {
+            let value = self.value(expr);
+            (TokenStream::new(), quote!((#value)))
+        }
+    }
+
+    /// Records the variable and all its accessed fields, as they can be evaluated multiple times.
+    fn variable(&mut self, expr: &Expr) -> TokenStream {
+        match expr {
+            Expr::Field(field) => {
+                let mut records = self.variable(&field.base);
+                self.print(".");
+                let column = self.column();
+                self.print(&text(&field.member));
+                records.extend(self.record(column, quote!(&#expr)));
+                records
+            }
+            _ => {
+                let column = self.column();
+                self.print(&text(expr));
+                self.record(column, quote!(&#expr))
+            }
+        }
+    }
+
+    fn arguments<'a>(&mut self, args: impl Iterator<Item = &'a Expr>) -> Vec<TokenStream> {
+        self.print("(");
+        let mut rewritten = Vec::new();
+        for (index, arg) in args.enumerate() {
+            if index > 0 {
+                self.print(", ");
+            }
+            rewritten.push(self.value(arg));
+        }
+        self.print(")");
+        rewritten
+    }
+
+    fn binary(&mut self, binary: &syn::ExprBinary) -> TokenStream {
+        let op = &binary.op;
+        match op {
+            BinOp::Eq(_)
+            | BinOp::Ne(_)
+            | BinOp::Lt(_)
+            | BinOp::Le(_)
+            | BinOp::Gt(_)
+            | BinOp::Ge(_) => {
+                let left = self.reference(&binary.left);
+                let column = self.operator(op);
+                let right = self.reference(&binary.right);
+                let (left_val, right_val) = (
+                    Ident::new("left_val", Span::mixed_site()),
+                    Ident::new("right_val", Span::mixed_site()),
+                );
+                let result = self.recorded(column, quote!(*#left_val #op *#right_val));
+                quote! {
+                    match (#left, #right) {
+                        (#left_val, #right_val) => #result,
+                    }
+                }
+            }
+            BinOp::And(_) | BinOp::Or(_) => {
+                let left = self.value(&binary.left);
+                let column = self.operator(op);
+                let right = self.value(&binary.right);
+                self.recorded(column, quote!((#left) #op (#right)))
+            }
+            BinOp::Add(_)
+            | BinOp::Sub(_)
+            | BinOp::Mul(_)
+            | BinOp::Div(_)
+            | BinOp::Rem(_)
+            | BinOp::BitXor(_)
+            | BinOp::BitAnd(_)
+            | BinOp::BitOr(_)
+            | BinOp::Shl(_)
+            | BinOp::Shr(_) => {
+                let left = self.value(&binary.left);
+                let column = self.operator(op);
+                let right = self.value(&binary.right);
+                self.recorded(column, quote!((#left) #op (#right)))
+            }
+            _ => {
+                let expr = Expr::Binary(binary.clone());
+                self.leaf(&expr)
+            }
+        }
+    }
+
+    /// Prints binary operator and returns its column.
+    fn operator(&mut self, op: &BinOp) -> usize {
+        self.print(" ");
+        let column = self.column();
+        self.print(&text(op));
+        self.print(" ");
+        column
+    }
+
+    /// Prints expression, which is not rewritten.
+    fn leaf(&mut self, expr: &Expr) -> TokenStream {
+        self.print(&text(expr));
+        expr.to_token_stream()
+    }
+}
+
+/// Returns `true` for local variables and their fields, which can be borrowed and evaluated again.
+///
+/// Paths starting with an uppercase letter are constants or unit variants, they are not recorded,
+/// as their types can be inferred from the surrounding expression only.
+fn is_variable(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(ExprPath {
+            qself: None, path, ..
+        }) => {
+            path.leading_colon.is_none()
+                && path.segments.len() == 1
+                && path.segments[0].arguments.is_empty()
+                && !path.segments[0]
+                    .ident
+                    .to_string()
+                    .starts_with(|c: char| c.is_uppercase())
+        }
+        Expr::Field(field) => is_variable(&field.base),
+        _ => false,
+    }
+}
+
+/// Returns `true` for expressions, which can be borrowed without moving them.
+fn is_place(expr: &Expr) -> bool {
+    match expr {
+        Expr::Index(_) => true,
+        Expr::Unary(unary) => matches!(unary.op, UnOp::Deref(_)),
+        Expr::Field(field) => is_place(&field.base),
+        Expr::Paren(paren) => is_place(&paren.expr),
+        _ => is_variable(expr),
+    }
+}
+
+fn is_literal(expr: &Expr) -> bool {
+    matches!(expr, Expr::Lit(_))
+}
+
+/// Prints tokens with the spacing close to the usual formatting.
+fn text<T: ToTokens>(tokens: &T) -> String {
+    let mut output = String::new();
+    print_tokens(tokens.to_token_stream(), &mut output);
+    output
+}
+
+fn print_tokens(tokens: TokenStream, output: &mut String) {
+    // Whether the space should be printed before the next token.
+    let mut space = false;
+    // Whether the previous token allows the next one to be a call or an index.
+    let mut callable = false;
+    // Whether the previous token is the first character of a multi-character operator.
+    let mut joined = false;
+    // How many generic brackets `::<` are open.
+    let mut generics = 0;
+    // Whether the parameters of a closure are printed.
+    let mut closure = false;
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                if space {
+                    output.push(' ');
+                }
+                output.push_str(&ident.to_string());
+                space = true;
+                callable = true;
+                joined = false;
+            }
+            TokenTree::Literal(literal) => {
+                if space {
+                    output.push(' ');
+                }
+                output.push_str(&literal.to_string());
+                space = true;
+                callable = false;
+                joined = false;
+            }
+            TokenTree::Punct(punct) => {
+                let ch = punct.as_char();
+                let joint = punct.spacing() == Spacing::Joint;
+                let mut next_joined = false;
+                let after_operand = callable;
+                callable = false;
+                match ch {
+                    '<' if generics > 0 || output.ends_with("::") => {
+                        output.push(ch);
+                        generics += 1;
+                        space = false;
+                    }
+                    '>' if generics > 0 => {
+                        output.push(ch);
+                        generics -= 1;
+                        space = generics == 0;
+                        callable = true;
+                    }
+                    _ if joined => {
+                        output.push(ch);
+                        space = !joint;
+                        next_joined = joint;
+                    }
+                    '.' | ':' | '\'' => {
+                        output.push(ch);
+                        space = ch == ':' && !joint && !output.ends_with("::");
+                    }
+                    ',' | ';' => {
+                        output.push(ch);
+                        space = true;
+                    }
+                    '|' if closure || !after_operand => {
+                        // Closure parameters.
+                        if space && !closure {
+                            output.push(' ');
+                        }
+                        output.push(ch);
+                        space = closure;
+                        closure = !closure;
+                    }
+                    '!' if !joint && after_operand && output.ends_with(is_ident_char) => {
+                        // Macro invocation.
+                        output.push(ch);
+                        space = false;
+                        callable = true;
+                    }
+                    '&' | '!' | '*' | '-' if !joint && !after_operand => {
+                        // Prefix operators.
+                        if space {
+                            output.push(' ');
+                        }
+                        output.push(ch);
+                        space = false;
+                    }
+                    _ => {
+                        if space {
+                            output.push(' ');
+                        }
+                        output.push(ch);
+                        space = !joint;
+                        next_joined = joint;
+                        callable = ch == '?';
+                    }
+                }
+                joined = next_joined;
+            }
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{ ", " }"),
+                    Delimiter::None => ("", ""),
+                };
+                if space && !(callable && group.delimiter() != Delimiter::Brace) {
+                    output.push(' ');
+                }
+                output.push_str(open);
+                print_tokens(group.stream(), output);
+                output.push_str(close);
+                space = true;
+                callable = true;
+                joined = false;
+            }
+        }
+    }
+}
+
+fn is_ident_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
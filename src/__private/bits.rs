@@ -0,0 +1,135 @@
+use core::fmt::{self, Write};
+use core::ops::{BitAnd, BitXor, Not};
+
+/// Integer types, which bits can be checked.
+pub trait Bits:
+    Copy
+    + PartialEq
+    + BitAnd<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + fmt::Binary
+    + fmt::LowerHex
+{
+    /// Value without any bits set.
+    const ZERO: Self;
+    /// Size of the type in bits.
+    const BITS: usize;
+}
+
+macro_rules! impl_bits {
+    ($($ty:ty),+) => {
+        $(
+            impl Bits for $ty {
+                const ZERO: Self = 0;
+                const BITS: usize = ::core::mem::size_of::<$ty>() * 8;
+            }
+        )+
+    };
+}
+
+impl_bits!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Result of checking the bits of the value.
+#[derive(Debug)]
+pub struct BitsReport<T> {
+    value: T,
+    expected: Option<T>,
+    mask: T,
+    // Bits, which fail the check.
+    failed: T,
+}
+
+impl<T: Bits> BitsReport<T> {
+    /// Returns `true` if all bits passed the check.
+    pub fn is_ok(&self) -> bool {
+        self.failed == T::ZERO
+    }
+}
+
+/// Checks that all bits of the `mask` are set in the `value`.
+pub fn bits_set<T: Bits>(value: T, mask: T) -> BitsReport<T> {
+    BitsReport {
+        value,
+        expected: None,
+        mask,
+        failed: mask & !value,
+    }
+}
+
+/// Checks that all bits of the `mask` are clear in the `value`.
+pub fn bits_clear<T: Bits>(value: T, mask: T) -> BitsReport<T> {
+    BitsReport {
+        value,
+        expected: None,
+        mask,
+        failed: value & mask,
+    }
+}
+
+/// Checks that the bits of the `mask` are the same in the `value` and the `expected` value.
+pub fn bits_eq<T: Bits>(value: T, expected: T, mask: T) -> BitsReport<T> {
+    BitsReport {
+        value,
+        expected: Some(expected),
+        mask,
+        failed: (value ^ expected) & mask,
+    }
+}
+
+/// Writes the labeled value in binary and hex, with the binary digits aligned for all labels.
+fn write_value<T: Bits>(f: &mut fmt::Formatter<'_>, label: &str, value: T) -> fmt::Result {
+    write!(
+        f,
+        "\n    {:<10}`{:#0binary$b}` (`{:#0hex$x}`)",
+        label,
+        value,
+        value,
+        binary = T::BITS + 2,
+        hex = T::BITS / 4 + 2
+    )
+}
+
+impl<T: Bits> fmt::Display for BitsReport<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_value(f, "value:", self.value)?;
+        f.write_char(',')?;
+        if let Some(expected) = self.expected {
+            write_value(f, "expected:", expected)?;
+            f.write_char(',')?;
+        }
+        write_value(f, "mask:", self.mask)?;
+
+        // Failed bits are marked under the binary digits, which start after the label and `0b`.
+        f.write_char('\n')?;
+        let mut markers = Markers {
+            f,
+            spaces: 4 + 10 + 1 + 2,
+        };
+        write!(markers, "{:0width$b}", self.failed, width = T::BITS)
+    }
+}
+
+/// Writes `^` for every set bit of the binary number and spaces for the others,
+/// without the trailing ones.
+struct Markers<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    spaces: usize,
+}
+
+impl<'a, 'b> fmt::Write for Markers<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for digit in s.chars() {
+            if digit == '1' {
+                for _ in 0..self.spaces {
+                    self.f.write_char(' ')?;
+                }
+                self.spaces = 0;
+                self.f.write_char('^')?;
+            } else {
+                self.spaces += 1;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,268 @@
+use core::{fmt, mem, str};
+
+/// How many of the rows with different bytes are reported by `assert_bytes_eq!`.
+pub const MAX_REPORTED_ROWS: usize = 16;
+
+/// Amount of bytes in a row of the hexdump, for each side.
+const ROW_BYTES: usize = 8;
+
+/// Least amount of hex digits in the offset column.
+const MIN_OFFSET_DIGITS: usize = 8;
+
+/// Most amount of hex digits in the offset column, enough for any `usize` offset.
+const MAX_OFFSET_DIGITS: usize = 2 * mem::size_of::<usize>();
+
+/// Width of a side: hex bytes, ASCII column and the separator.
+const SIDE_WIDTH: usize = ROW_BYTES * 3 - 1 + 2 + ROW_BYTES + 2 + 2;
+
+/// Width of the widest line: the offset column with its indentation and padding, and both sides.
+const LINE_WIDTH: usize = 4 + MAX_OFFSET_DIGITS + 2 + 2 * SIDE_WIDTH;
+
+/// Difference of two byte slices, which is displayed as a side-by-side hexdump.
+#[derive(Debug)]
+pub struct BytesDiff<'a> {
+    left: &'a [u8],
+    right: &'a [u8],
+    first: Option<usize>,
+}
+
+/// Compares the bytes of both values.
+pub fn bytes_diff<'a, L, R>(left: &'a L, right: &'a R) -> BytesDiff<'a>
+where
+    L: ?Sized + AsRef<[u8]>,
+    R: ?Sized + AsRef<[u8]>,
+{
+    let (left, right) = (left.as_ref(), right.as_ref());
+    let first = left
+        .iter()
+        .zip(right)
+        .position(|(left, right)| left != right)
+        .or_else(|| {
+            if left.len() == right.len() {
+                None
+            } else {
+                Some(left.len().min(right.len()))
+            }
+        });
+    BytesDiff { left, right, first }
+}
+
+impl<'a> BytesDiff<'a> {
+    /// Returns `true` if both values have the same bytes.
+    pub fn is_ok(&self) -> bool {
+        self.first.is_none()
+    }
+
+    /// Returns `true` if the row has bytes on any of the sides.
+    fn has_row(&self, row: usize) -> bool {
+        row * ROW_BYTES < self.left.len().max(self.right.len())
+    }
+
+    /// Returns `true` if the byte at `offset` is different or missing on one of the sides.
+    fn differs(&self, offset: usize) -> bool {
+        self.left.get(offset) != self.right.get(offset)
+    }
+
+    fn row_differs(&self, row: usize) -> bool {
+        (row * ROW_BYTES..(row + 1) * ROW_BYTES).any(|offset| self.differs(offset))
+    }
+
+    /// Returns `true` if the row is reported as a context of the nearby differences.
+    fn is_context(&self, row: usize) -> bool {
+        (row > 0 && self.row_differs(row - 1))
+            || (self.has_row(row + 1) && self.row_differs(row + 1))
+    }
+
+    /// Returns amount of hex digits of the offset column, which fits offsets of all rows.
+    fn offset_digits(&self) -> usize {
+        let last = self.left.len().max(self.right.len()).saturating_sub(1);
+        let mut digits = MIN_OFFSET_DIGITS;
+        while digits < MAX_OFFSET_DIGITS && last >> (4 * digits) != 0 {
+            digits += 1;
+        }
+        digits
+    }
+
+    fn write_row(&self, f: &mut fmt::Formatter<'_>, row: usize, digits: usize) -> fmt::Result {
+        let offset = row * ROW_BYTES;
+        let (left, right) = (row_bytes(self.left, offset), row_bytes(self.right, offset));
+        write!(f, "\n{}", Line::bytes(offset, digits, left, right).as_str())?;
+        if left != right {
+            write!(f, "\n{}", Line::markers(digits, left, right).as_str())?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the bytes of the row, which starts at `offset`.
+fn row_bytes(bytes: &[u8], offset: usize) -> &[u8] {
+    match bytes.get(offset..) {
+        Some(bytes) => &bytes[..bytes.len().min(ROW_BYTES)],
+        None => &[],
+    }
+}
+
+impl<'a> fmt::Display for BytesDiff<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let first = match self.first {
+            Some(first) => first,
+            None => return Ok(()),
+        };
+        write!(
+            f,
+            "\n    first difference at offset {} ({:#x}), left: {} bytes, right: {} bytes",
+            first,
+            first,
+            self.left.len(),
+            self.right.len()
+        )?;
+
+        let digits = self.offset_digits();
+        let mut reported = 0;
+        let mut skipped = false;
+        let mut differing = 0;
+        for row in (0..).take_while(|row| self.has_row(*row)) {
+            let differs = self.row_differs(row);
+            if differs {
+                differing += 1;
+            }
+            if reported == MAX_REPORTED_ROWS {
+                continue;
+            }
+            if differs || self.is_context(row) {
+                self.write_row(f, row, digits)?;
+                if differs {
+                    reported += 1;
+                }
+                skipped = false;
+            } else if !skipped {
+                f.write_str("\n    ...")?;
+                skipped = true;
+            }
+        }
+        if differing > reported {
+            write!(
+                f,
+                "\n    ... and {} more rows with differences",
+                differing - reported
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Line of the hexdump, which is formatted without allocations.
+struct Line {
+    buffer: [u8; LINE_WIDTH],
+    len: usize,
+}
+
+impl Line {
+    fn new() -> Self {
+        Line {
+            buffer: [0; LINE_WIDTH],
+            len: 0,
+        }
+    }
+
+    /// Formats the row of `left` and `right` bytes, which starts at `offset`.
+    fn bytes(offset: usize, digits: usize, left: &[u8], right: &[u8]) -> Self {
+        let mut line = Line::new();
+        line.write(format_args!("    {:01$x}  ", offset, digits));
+        for bytes in [left, right].iter() {
+            for index in 0..ROW_BYTES {
+                if index > 0 {
+                    line.push(b' ');
+                }
+                match bytes.get(index) {
+                    Some(byte) => line.write(format_args!("{:02x}", byte)),
+                    None => line.extend(b"  "),
+                }
+            }
+            line.extend(b"  |");
+            for index in 0..ROW_BYTES {
+                line.push(match bytes.get(index) {
+                    Some(byte) if byte.is_ascii_graphic() || *byte == b' ' => *byte,
+                    Some(_) => b'.',
+                    None => b' ',
+                });
+            }
+            line.extend(b"|  ");
+        }
+        line
+    }
+
+    /// Formats the markers under the different bytes of the row.
+    fn markers(digits: usize, left: &[u8], right: &[u8]) -> Self {
+        let differs = |index: usize| left.get(index) != right.get(index);
+        let mut line = Line::new();
+        for _ in 0..4 + digits + 2 {
+            line.push(b' ');
+        }
+        for _ in 0..2 {
+            for index in 0..ROW_BYTES {
+                if index > 0 {
+                    line.push(b' ');
+                }
+                line.extend(if differs(index) { b"^^" } else { b"  " });
+            }
+            line.extend(b"   ");
+            for index in 0..ROW_BYTES {
+                line.push(if differs(index) { b'^' } else { b' ' });
+            }
+            line.extend(b"   ");
+        }
+        line
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.buffer[self.len] = byte;
+        self.len += 1;
+    }
+
+    fn extend(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.push(*byte);
+        }
+    }
+
+    fn write(&mut self, args: fmt::Arguments<'_>) {
+        // Writing into the buffer can't fail, as the line width is fixed.
+        let _ = fmt::Write::write_fmt(self, args);
+    }
+
+    /// Returns the line without the trailing whitespace.
+    fn as_str(&self) -> &str {
+        let line = str::from_utf8(&self.buffer[..self.len]).unwrap_or_default();
+        line.trim_end()
+    }
+}
+
+impl fmt::Write for Line {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.extend(s.as_bytes());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use super::Line;
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn formats_large_offsets() {
+        let offset = !7_usize;
+        assert_eq!(
+            Line::bytes(offset, 16, b"ab\0", b"abc").as_str(),
+            "    fffffffffffffff8  61 62 00                 |ab.     |  \
+             61 62 63                 |abc     |"
+        );
+        assert_eq!(
+            Line::markers(16, b"ab\0", b"abc").as_str(),
+            "                            ^^                    ^              \
+             ^^                    ^"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use core::fmt;
+
+/// How many of the most different elements are reported by `assert_all_close!`.
+pub const WORST_ELEMENTS: usize = 5;
+
+/// Numeric element, which can be compared by `assert_all_close!`.
+pub trait Numeric {
+    /// Relative tolerance, used if none was provided.
+    const DEFAULT_RELATIVE_TOLERANCE: f64;
+
+    /// Returns value converted to `f64`.
+    ///
+    /// Conversion is lossless for all types except for 64-bit and pointer-sized integers,
+    /// which are rounded to the nearest `f64` value if their magnitude exceeds `2^53`.
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! numeric {
+    ($tolerance:expr, $($ty:ty),+) => {
+        $(
+            impl Numeric for $ty {
+                const DEFAULT_RELATIVE_TOLERANCE: f64 = $tolerance;
+
+                fn to_f64(&self) -> f64 {
+                    f64::from(*self)
+                }
+            }
+        )+
+    };
+    (lossy $tolerance:expr, $($ty:ty),+) => {
+        $(
+            impl Numeric for $ty {
+                const DEFAULT_RELATIVE_TOLERANCE: f64 = $tolerance;
+
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )+
+    };
+}
+
+numeric!(1e-5, f32);
+numeric!(1e-9, f64);
+numeric!(0.0, i8, i16, i32, u8, u16, u32);
+numeric!(lossy 0.0, i64, isize, u64, usize);
+
+impl<T: ?Sized + Numeric> Numeric for &T {
+    const DEFAULT_RELATIVE_TOLERANCE: f64 = T::DEFAULT_RELATIVE_TOLERANCE;
+
+    fn to_f64(&self) -> f64 {
+        (**self).to_f64()
+    }
+}
+
+/// Collection or iterator of the `assert_all_close!` arguments.
+#[derive(Debug)]
+pub struct Elements<'a, T: ?Sized>(pub &'a T);
+
+/// Iterates over collection elements by reference.
+pub trait ViaRef<'a, T: ?Sized> {
+    /// Elements iterator.
+    type Iter: Iterator;
+
+    /// Returns iterator over elements.
+    fn elements(&self) -> Self::Iter;
+}
+
+impl<'a, T: ?Sized> ViaRef<'a, T> for Elements<'a, T>
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: Numeric,
+{
+    type Iter = <&'a T as IntoIterator>::IntoIter;
+
+    fn elements(&self) -> Self::Iter {
+        self.0.into_iter()
+    }
+}
+
+/// Iterates over a copy of the iterator.
+pub trait ViaClone<'a, T> {
+    /// Elements iterator.
+    type Iter: Iterator;
+
+    /// Returns iterator over elements.
+    fn elements(&self) -> Self::Iter;
+}
+
+impl<'a, T> ViaClone<'a, T> for &Elements<'a, T>
+where
+    T: Iterator + Clone,
+    T::Item: Numeric,
+{
+    type Iter = T;
+
+    fn elements(&self) -> Self::Iter {
+        self.0.clone()
+    }
+}
+
+/// Element, which is out of tolerance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Offender {
+    index: usize,
+    left: f64,
+    right: f64,
+    diff: f64,
+}
+
+impl Offender {
+    fn is_worse_than(&self, other: &Offender) -> bool {
+        // NaN difference is the worst one possible.
+        self.diff > other.diff || (self.diff.is_nan() && !other.diff.is_nan())
+    }
+}
+
+/// Result of the element-wise comparison.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AllClose {
+    left_len: usize,
+    right_len: usize,
+    rel: f64,
+    abs: f64,
+    mismatched: usize,
+    worst: [Option<Offender>; WORST_ELEMENTS],
+}
+
+impl AllClose {
+    /// Returns `true` if both sides have the same length and all elements are within tolerance.
+    pub fn is_ok(&self) -> bool {
+        self.left_len == self.right_len && self.mismatched == 0
+    }
+
+    fn record(&mut self, offender: Offender) {
+        self.mismatched += 1;
+        let mut candidate = offender;
+        for slot in self.worst.iter_mut() {
+            match *slot {
+                Some(current) if !candidate.is_worse_than(&current) => continue,
+                Some(current) => {
+                    *slot = Some(candidate);
+                    candidate = current;
+                }
+                None => {
+                    *slot = Some(candidate);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+fn abs(value: f64) -> f64 {
+    if value < 0.0 {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Returns `true` if the elements with the given difference are within tolerance.
+///
+/// Non-finite elements are close only if they are equal, so infinities of the same sign match,
+/// but NaN never does.
+fn is_close(left: f64, right: f64, diff: f64, rel: f64, abs_tolerance: f64) -> bool {
+    if !left.is_finite() || !right.is_finite() {
+        #[allow(clippy::float_cmp)]
+        return left == right;
+    }
+    let magnitude = if abs(left) > abs(right) {
+        abs(left)
+    } else {
+        abs(right)
+    };
+    let tolerance = if rel * magnitude > abs_tolerance {
+        rel * magnitude
+    } else {
+        abs_tolerance
+    };
+    diff <= tolerance
+}
+
+/// Compares `left` and `right` elements pairwise.
+///
+/// Elements are close if `|left - right| <= max(rel * max(|left|, |right|), abs)`.
+/// Relative tolerance defaults to the precision of the less precise element type of both sides,
+/// absolute one defaults to zero.
+pub fn all_close<L, R>(left: L, right: R, rel: Option<f64>, abs_tolerance: Option<f64>) -> AllClose
+where
+    L: Iterator,
+    L::Item: Numeric,
+    R: Iterator,
+    R::Item: Numeric,
+{
+    let default_rel = if <L::Item as Numeric>::DEFAULT_RELATIVE_TOLERANCE
+        > <R::Item as Numeric>::DEFAULT_RELATIVE_TOLERANCE
+    {
+        <L::Item as Numeric>::DEFAULT_RELATIVE_TOLERANCE
+    } else {
+        <R::Item as Numeric>::DEFAULT_RELATIVE_TOLERANCE
+    };
+    let mut result = AllClose {
+        left_len: 0,
+        right_len: 0,
+        rel: rel.unwrap_or(default_rel),
+        abs: abs_tolerance.unwrap_or(0.0),
+        mismatched: 0,
+        worst: [None; WORST_ELEMENTS],
+    };
+    let mut left = left.fuse();
+    let mut right = right.fuse();
+
+    loop {
+        match (left.next(), right.next()) {
+            (Some(l), Some(r)) => {
+                let (l, r) = (l.to_f64(), r.to_f64());
+                let diff = abs(l - r);
+                if !is_close(l, r, diff, result.rel, result.abs) {
+                    result.record(Offender {
+                        index: result.left_len,
+                        left: l,
+                        right: r,
+                        diff,
+                    });
+                }
+                result.left_len += 1;
+                result.right_len += 1;
+            }
+            (Some(_), None) => result.left_len += 1,
+            (None, Some(_)) => result.right_len += 1,
+            (None, None) => break,
+        }
+    }
+
+    result
+}
+
+impl fmt::Display for AllClose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.left_len != self.right_len {
+            return write!(
+                f,
+                "\n    shape mismatch: left has {} elements, right has {} elements",
+                self.left_len, self.right_len
+            );
+        }
+
+        write!(
+            f,
+            "\n    mismatched elements: {} of {},\n    tolerance: rel = {:?}, abs = {:?}",
+            self.mismatched, self.left_len, self.rel, self.abs
+        )?;
+        for offender in self.worst.iter().filter_map(|offender| offender.as_ref()) {
+            write!(
+                f,
+                ",\n    [{}]: left = {:?}, right = {:?}, diff = {:?}",
+                offender.index, offender.left, offender.right, offender.diff
+            )?;
+        }
+        Ok(())
+    }
+}
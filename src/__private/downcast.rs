@@ -0,0 +1,150 @@
+use core::any::Any;
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Type-erased value, which can be downcasted to the `T` type.
+pub trait Downcast<T> {
+    /// Returns reference to the value, if it is of the `T` type.
+    fn downcast(&self) -> Option<&T>;
+
+    /// Writes description of the actual value.
+    fn fmt_actual(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Writes the `&str` or `String` value, which are the usual panic payloads,
+/// or the type identifiers of the `value` and the `T` type.
+fn fmt_any<T: Any>(value: &dyn Any, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if let Some(value) = value.downcast_ref::<&'static str>() {
+        return write!(f, "`&str` value `{:?}`", value);
+    }
+    #[cfg(feature = "alloc")]
+    {
+        if let Some(value) = value.downcast_ref::<String>() {
+            return write!(f, "`String` value `{:?}`", value);
+        }
+    }
+    #[cfg(rustc_1_38)]
+    {
+        write!(
+            f,
+            "value of another type with `{:?}`, while `{}` has `{:?}`",
+            Any::type_id(value),
+            core::any::type_name::<T>(),
+            core::any::TypeId::of::<T>()
+        )
+    }
+    #[cfg(not(rustc_1_38))]
+    {
+        f.write_str("value of another type")
+    }
+}
+
+macro_rules! downcast_any {
+    ($($ty:ty),+) => {
+        $(
+            impl<T: Any> Downcast<T> for $ty {
+                fn downcast(&self) -> Option<&T> {
+                    self.downcast_ref()
+                }
+
+                fn fmt_actual(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_any::<T>(self, f)
+                }
+            }
+        )+
+    };
+}
+
+downcast_any!(dyn Any, dyn Any + Send, dyn Any + Send + Sync);
+
+#[cfg(feature = "std")]
+macro_rules! downcast_error {
+    ($($ty:ty),+) => {
+        $(
+            impl<T: Error + 'static> Downcast<T> for $ty {
+                fn downcast(&self) -> Option<&T> {
+                    self.downcast_ref()
+                }
+
+                fn fmt_actual(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "error `{:?}`", self)
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "std")]
+downcast_error!(
+    dyn Error + 'static,
+    dyn Error + Send + 'static,
+    dyn Error + Send + Sync + 'static
+);
+
+impl<T, D: ?Sized + Downcast<T>> Downcast<T> for &D {
+    fn downcast(&self) -> Option<&T> {
+        (**self).downcast()
+    }
+
+    fn fmt_actual(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt_actual(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, D: ?Sized + Downcast<T>> Downcast<T> for Box<D> {
+    fn downcast(&self) -> Option<&T> {
+        (**self).downcast()
+    }
+
+    fn fmt_actual(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt_actual(f)
+    }
+}
+
+/// Returns reference to the `value`, if it is of the `T` type.
+pub fn downcast<T, D: ?Sized + Downcast<T>>(value: &D) -> Option<&T> {
+    value.downcast()
+}
+
+/// Description of the actual value, which is not of the `T` type.
+pub struct Actual<'a, T, D: ?Sized> {
+    value: &'a D,
+    target: core::marker::PhantomData<T>,
+}
+
+/// Returns description of the actual `value`, which is not of the `T` type.
+pub fn actual<T, D: ?Sized + Downcast<T>>(value: &D) -> Actual<'_, T, D> {
+    Actual {
+        value,
+        target: core::marker::PhantomData,
+    }
+}
+
+impl<'a, T, D: ?Sized> fmt::Debug for Actual<'a, T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Actual { .. }")
+    }
+}
+
+impl<'a, T, D: ?Sized + Downcast<T>> fmt::Display for Actual<'a, T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt_actual(f)
+    }
+}
+
+/// Implemented only for the same type.
+pub trait Is<T> {}
+
+impl<T> Is<T> for T {}
+
+/// Returns the `value`, which should be exactly of the `T` type.
+pub fn is<T, U: Is<T>>(value: U) -> U {
+    value
+}
@@ -0,0 +1,200 @@
+use core::fmt;
+
+use handler::{handle, Failure};
+#[cfg(feature = "std")]
+use invariants::{is_checking, unwind, violation, Violation};
+
+/// Passes the failed assertion to the handler and panics with its message.
+///
+/// If the invariants are checked on the current thread, the failure is reported as the violation instead.
+#[track_caller]
+pub fn fail(assertion: &'static str, message: fmt::Arguments<'_>) -> ! {
+    report(&Failure::new(assertion, message));
+    // Messages without arguments are `&str` panic payloads, same as with `panic!` macro.
+    #[cfg(feature = "std")]
+    {
+        if let Some(message) = message.as_str() {
+            std::panic::panic_any(message)
+        }
+    }
+    panic!("{}", message)
+}
+
+/// Same as `fail`, but also passes the compared values and their expressions, if any, to the handler.
+#[track_caller]
+pub fn fail_cmp(
+    assertion: &'static str,
+    expressions: Option<(&'static str, &'static str)>,
+    left: &dyn fmt::Debug,
+    right: &dyn fmt::Debug,
+    message: fmt::Arguments<'_>,
+) -> ! {
+    let failure = Failure::new(assertion, message).with_values(left, right);
+    failed(&match expressions {
+        Some((left, right)) => failure.with_expressions(left, right),
+        None => failure,
+    })
+}
+
+/// Reports the failure and panics with its message.
+#[track_caller]
+fn failed(failure: &Failure<'_>) -> ! {
+    report(failure);
+    panic!("{}", failure)
+}
+
+/// Passes the failure to the invariants sink or to the failure handler.
+fn report(failure: &Failure<'_>) {
+    #[cfg(feature = "std")]
+    {
+        if is_checking() {
+            unwind(failure);
+        }
+    }
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    ::events::emit(failure);
+    handle(failure);
+}
+
+/// Message of the failed equality or inequality comparison.
+struct Compared<'a, T: ?Sized, U: ?Sized> {
+    op: &'static str,
+    left: &'a T,
+    right: &'a U,
+    message: Option<fmt::Arguments<'a>>,
+}
+
+impl<'a, T, U> fmt::Display for Compared<'a, T, U>
+where
+    T: ?Sized + fmt::Debug,
+    U: ?Sized + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            r#"assertion failed: `(left {} right)`
+    left: `{:?}`,
+    right: `{:?}`"#,
+            self.op, self.left, self.right
+        )?;
+        match self.message {
+            Some(message) => write!(f, ": {}", message),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Passes the failed equality assertion to the handler and panics with the same message.
+#[track_caller]
+pub fn fail_eq<T, U>(
+    assertion: &'static str,
+    left: &T,
+    right: &U,
+    message: Option<fmt::Arguments<'_>>,
+) -> !
+where
+    T: ?Sized + fmt::Debug,
+    U: ?Sized + fmt::Debug,
+{
+    let compared = Compared {
+        op: "==",
+        left,
+        right,
+        message,
+    };
+    failed(&Failure::new(assertion, format_args!("{}", compared)).with_values(&left, &right))
+}
+
+/// Passes the failed inequality assertion to the handler and panics with the same message.
+#[track_caller]
+pub fn fail_ne<T, U>(
+    assertion: &'static str,
+    left: &T,
+    right: &U,
+    message: Option<fmt::Arguments<'_>>,
+) -> !
+where
+    T: ?Sized + fmt::Debug,
+    U: ?Sized + fmt::Debug,
+{
+    let compared = Compared {
+        op: "!=",
+        left,
+        right,
+        message,
+    };
+    failed(&Failure::new(assertion, format_args!("{}", compared)).with_values(&left, &right))
+}
+
+/// Passes the failed assertion to the invariants sink and returns it as the violation,
+/// same as `fail`, but without panicking.
+#[cfg(feature = "std")]
+#[track_caller]
+pub fn violate<T>(assertion: &'static str, message: fmt::Arguments<'_>) -> Result<T, Violation> {
+    Err(violation(&Failure::new(assertion, message)))
+}
+
+/// Same as `fail_cmp`, but returns the violation instead of panicking.
+#[cfg(feature = "std")]
+#[track_caller]
+pub fn violate_cmp<T>(
+    assertion: &'static str,
+    expressions: Option<(&'static str, &'static str)>,
+    left: &dyn fmt::Debug,
+    right: &dyn fmt::Debug,
+    message: fmt::Arguments<'_>,
+) -> Result<T, Violation> {
+    let failure = Failure::new(assertion, message).with_values(left, right);
+    Err(violation(&match expressions {
+        Some((left, right)) => failure.with_expressions(left, right),
+        None => failure,
+    }))
+}
+
+/// Same as `fail_eq`, but returns the violation instead of panicking.
+#[cfg(feature = "std")]
+#[track_caller]
+pub fn violate_eq<T, U, V>(
+    assertion: &'static str,
+    left: &T,
+    right: &U,
+    message: Option<fmt::Arguments<'_>>,
+) -> Result<V, Violation>
+where
+    T: ?Sized + fmt::Debug,
+    U: ?Sized + fmt::Debug,
+{
+    let compared = Compared {
+        op: "==",
+        left,
+        right,
+        message,
+    };
+    Err(violation(
+        &Failure::new(assertion, format_args!("{}", compared)).with_values(&left, &right),
+    ))
+}
+
+/// Same as `fail_ne`, but returns the violation instead of panicking.
+#[cfg(feature = "std")]
+#[track_caller]
+pub fn violate_ne<T, U, V>(
+    assertion: &'static str,
+    left: &T,
+    right: &U,
+    message: Option<fmt::Arguments<'_>>,
+) -> Result<V, Violation>
+where
+    T: ?Sized + fmt::Debug,
+    U: ?Sized + fmt::Debug,
+{
+    let compared = Compared {
+        op: "!=",
+        left,
+        right,
+        message,
+    };
+    Err(violation(
+        &Failure::new(assertion, format_args!("{}", compared)).with_values(&left, &right),
+    ))
+}
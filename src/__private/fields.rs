@@ -0,0 +1,149 @@
+use core::fmt;
+
+use matchers::{self, Matcher};
+
+/// Single field check of the `assert_fields!` macro.
+pub trait Field {
+    /// Returns `true` if the field value is the expected one.
+    fn is_match(&self) -> bool;
+
+    /// Writes the report line for the mismatched field.
+    fn fmt_mismatch(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Field value and its expectation, either a plain value or a [`Matcher`].
+///
+/// [`Matcher`]: ../matchers/trait.Matcher.html
+#[derive(Debug)]
+pub struct FieldProbe<'a, T: ?Sized, E> {
+    path: &'static str,
+    actual: &'a T,
+    expected: &'a E,
+}
+
+impl<'a, T: ?Sized, E> FieldProbe<'a, T, E> {
+    /// Creates a new probe for the field at `path`.
+    pub fn new(path: &'static str, actual: &'a T, expected: &'a E) -> Self {
+        FieldProbe {
+            path,
+            actual,
+            expected,
+        }
+    }
+}
+
+/// Field, which is checked with a [`Matcher`].
+///
+/// [`Matcher`]: ../matchers/trait.Matcher.html
+#[derive(Debug)]
+pub struct MatcherField<'a, T: ?Sized, M> {
+    path: &'static str,
+    actual: &'a T,
+    matcher: &'a M,
+}
+
+impl<'a, T: ?Sized + fmt::Debug, M: Matcher<T>> Field for MatcherField<'a, T, M> {
+    fn is_match(&self) -> bool {
+        self.matcher.matches(self.actual)
+    }
+
+    fn fmt_mismatch(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: expected {}, got `{:?}`",
+            self.path,
+            matchers::description(self.matcher, self.actual),
+            self.actual
+        )
+    }
+}
+
+/// Field, which is compared with `==`.
+#[derive(Debug)]
+pub struct EqField<'a, T: ?Sized, E> {
+    path: &'static str,
+    actual: &'a T,
+    expected: &'a E,
+}
+
+impl<'a, T: ?Sized + PartialEq<E> + fmt::Debug, E: fmt::Debug> Field for EqField<'a, T, E> {
+    fn is_match(&self) -> bool {
+        *self.actual == *self.expected
+    }
+
+    fn fmt_mismatch(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: expected `{:?}`, got `{:?}`",
+            self.path, self.expected, self.actual
+        )
+    }
+}
+
+// Expectations are resolved with the auto-ref method lookup:
+// `(&probe).field()` picks `ViaMatcher` if the expected value is a `Matcher` for the field type,
+// and falls back to `ViaEq` (which requires one more auto-ref) otherwise.
+
+/// Converts probe into a [`MatcherField`].
+pub trait ViaMatcher<'a, T: ?Sized, M> {
+    /// Returns the field check.
+    fn field(&self) -> MatcherField<'a, T, M>;
+}
+
+impl<'a, T: ?Sized + fmt::Debug, M: Matcher<T>> ViaMatcher<'a, T, M> for FieldProbe<'a, T, M> {
+    fn field(&self) -> MatcherField<'a, T, M> {
+        MatcherField {
+            path: self.path,
+            actual: self.actual,
+            matcher: self.expected,
+        }
+    }
+}
+
+/// Converts probe into an [`EqField`].
+pub trait ViaEq<'a, T: ?Sized, E> {
+    /// Returns the field check.
+    fn field(&self) -> EqField<'a, T, E>;
+}
+
+impl<'a, T: ?Sized + PartialEq<E> + fmt::Debug, E: fmt::Debug> ViaEq<'a, T, E>
+    for &FieldProbe<'a, T, E>
+{
+    fn field(&self) -> EqField<'a, T, E> {
+        EqField {
+            path: self.path,
+            actual: self.actual,
+            expected: self.expected,
+        }
+    }
+}
+
+/// Coerces field check into a trait object.
+pub fn as_field<'a, F: Field + 'a>(field: &'a F) -> &'a (dyn Field + 'a) {
+    field
+}
+
+/// Returns `true` if all fields have the expected values.
+pub fn all_match(fields: &[&dyn Field]) -> bool {
+    fields.iter().all(|field| field.is_match())
+}
+
+/// Report lines for all mismatched fields.
+pub struct FieldReport<'a>(pub &'a [&'a dyn Field]);
+
+impl<'a> fmt::Display for FieldReport<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for field in self.0.iter().filter(|field| !field.is_match()) {
+            f.write_str("\n    ")?;
+            field.fmt_mismatch(f)?;
+            f.write_str(",")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Debug for FieldReport<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
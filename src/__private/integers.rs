@@ -0,0 +1,138 @@
+use core::fmt;
+
+/// Primitive integer types, which are checked by the arithmetic assertions.
+pub trait Integer: Copy + PartialEq + fmt::Debug {
+    /// Returns `true` if the value is a positive power of two.
+    fn is_power_of_two(self) -> bool;
+
+    /// Returns the remainder of dividing the value by the `divisor`,
+    /// or the value itself if the `divisor` is zero.
+    fn remainder(self, divisor: Self) -> Self;
+
+    /// Returns `true` if the value is zero.
+    fn is_zero(self) -> bool;
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),+) => {
+        $(
+            impl Integer for $ty {
+                fn is_power_of_two(self) -> bool {
+                    self > 0 && self & (self - 1) == 0
+                }
+
+                fn remainder(self, divisor: Self) -> Self {
+                    // Wrapping remainder is zero for `MIN % -1` instead of overflowing.
+                    if divisor == 0 {
+                        self
+                    } else {
+                        self.wrapping_rem(divisor)
+                    }
+                }
+
+                fn is_zero(self) -> bool {
+                    self == 0
+                }
+            }
+        )+
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Pointers and unsigned integers, which alignment can be checked.
+pub trait Address {
+    /// Returns the address as an integer.
+    fn address(&self) -> u128;
+}
+
+macro_rules! impl_address {
+    ($($ty:ty),+) => {
+        $(
+            impl Address for $ty {
+                fn address(&self) -> u128 {
+                    *self as u128
+                }
+            }
+        )+
+    };
+}
+
+impl_address!(u8, u16, u32, u64, usize);
+
+impl Address for u128 {
+    fn address(&self) -> u128 {
+        *self
+    }
+}
+
+impl<T: ?Sized> Address for *const T {
+    fn address(&self) -> u128 {
+        *self as *const u8 as usize as u128
+    }
+}
+
+impl<T: ?Sized> Address for *mut T {
+    fn address(&self) -> u128 {
+        *self as *const u8 as usize as u128
+    }
+}
+
+impl<T: ?Sized> Address for &T {
+    fn address(&self) -> u128 {
+        let pointer: *const T = *self;
+        pointer.address()
+    }
+}
+
+impl<T: ?Sized> Address for &mut T {
+    fn address(&self) -> u128 {
+        let pointer: *const T = &**self;
+        pointer.address()
+    }
+}
+
+/// Operand of the checked arithmetic operation.
+#[derive(Debug)]
+pub struct Operand<'a, T>(pub &'a T);
+
+/// Describes operands, which implement `Debug`.
+pub trait ViaDebug {
+    /// Returns the operand value to report.
+    fn operand(&self) -> Option<&dyn fmt::Debug>;
+}
+
+impl<'a, T: fmt::Debug> ViaDebug for Operand<'a, T> {
+    fn operand(&self) -> Option<&dyn fmt::Debug> {
+        Some(self.0)
+    }
+}
+
+/// Skips operands, which do not implement `Debug`, ex. closures.
+pub trait ViaOpaque {
+    /// Returns `None`, as the operand cannot be described.
+    fn operand(&self) -> Option<&dyn fmt::Debug>;
+}
+
+impl<'a, 'b, T> ViaOpaque for &'b Operand<'a, T> {
+    fn operand(&self) -> Option<&dyn fmt::Debug> {
+        None
+    }
+}
+
+/// Labeled operands of the checked arithmetic operation.
+#[derive(Debug)]
+pub struct Operands<'a>(pub &'a [(&'static str, Option<&'a dyn fmt::Debug>)]);
+
+impl<'a> fmt::Display for Operands<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for &(label, value) in self.0 {
+            if let Some(value) = value {
+                write!(f, "{}\n    {}: `{:?}`", separator, label, value)?;
+                separator = ",";
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use core::fmt;
+use std::io;
+
+/// Kind, OS code and message of the I/O error.
+#[derive(Debug)]
+pub struct IoErrorDetails<'a>(pub &'a io::Error);
+
+impl<'a> fmt::Display for IoErrorDetails<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\n    kind: `{:?}`,\n    os code: ", self.0.kind())?;
+        match self.0.raw_os_error() {
+            Some(code) => write!(f, "`{}`", code)?,
+            None => f.write_str("none")?,
+        }
+        write!(f, ",\n    message: `{}`", self.0)
+    }
+}
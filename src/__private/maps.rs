@@ -0,0 +1,232 @@
+use core::borrow::Borrow;
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Map, which entries can be visited.
+pub trait Entries {
+    /// Map key type.
+    type Key;
+    /// Map value type.
+    type Value;
+
+    /// Calls `f` for each entry until it returns `false`.
+    ///
+    /// Returns `true` if all calls returned `true`.
+    fn all_entries(&self, f: &mut dyn FnMut(&Self::Key, &Self::Value) -> bool) -> bool;
+}
+
+/// Map, which values can be looked up by the `Q` type key.
+pub trait Map<Q: ?Sized>: Entries {
+    /// Returns the value corresponding to the key.
+    fn lookup(&self, key: &Q) -> Option<&Self::Value>;
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Entries for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn all_entries(&self, f: &mut dyn FnMut(&K, &V) -> bool) -> bool {
+        self.iter().all(|(key, value)| f(key, value))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord + Borrow<Q>, V, Q: ?Sized + Ord> Map<Q> for BTreeMap<K, V> {
+    fn lookup(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq, V, S: BuildHasher> Entries for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    fn all_entries(&self, f: &mut dyn FnMut(&K, &V) -> bool) -> bool {
+        self.iter().all(|(key, value)| f(key, value))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, Q, S> Map<Q> for HashMap<K, V, S>
+where
+    K: Hash + Eq + Borrow<Q>,
+    Q: ?Sized + Hash + Eq,
+    S: BuildHasher,
+{
+    fn lookup(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<M: ?Sized + Entries> Entries for &M {
+    type Key = M::Key;
+    type Value = M::Value;
+
+    fn all_entries(&self, f: &mut dyn FnMut(&M::Key, &M::Value) -> bool) -> bool {
+        (**self).all_entries(f)
+    }
+}
+
+impl<M: ?Sized + Map<Q>, Q: ?Sized> Map<Q> for &M {
+    fn lookup(&self, key: &Q) -> Option<&M::Value> {
+        (**self).lookup(key)
+    }
+}
+
+/// Map and the key to look up.
+#[derive(Debug)]
+pub struct KeyProbe<'m, 'k, M: ?Sized, Q: ?Sized> {
+    map: &'m M,
+    key: &'k Q,
+}
+
+impl<'m, 'k, M: ?Sized, Q: ?Sized> KeyProbe<'m, 'k, M, Q> {
+    /// Creates a new probe for the `key` in the `map`.
+    pub fn new(map: &'m M, key: &'k Q) -> Self {
+        KeyProbe { map, key }
+    }
+}
+
+// Keys are resolved with the auto-ref method lookup:
+// `(&probe).lookup()` uses the key as is, if the map supports it,
+// and falls back to the dereferenced key, so `"a"` can be used for `String` keys.
+
+/// Looks up the key as is.
+pub trait ViaKey<'m, V> {
+    /// Returns the value corresponding to the key.
+    fn lookup(&self) -> Option<&'m V>;
+}
+
+impl<'m, 'k, M: ?Sized + Map<Q>, Q: ?Sized> ViaKey<'m, M::Value> for KeyProbe<'m, 'k, M, Q> {
+    fn lookup(&self) -> Option<&'m M::Value> {
+        self.map.lookup(self.key)
+    }
+}
+
+/// Looks up the dereferenced key.
+pub trait ViaKeyDeref<'m, V> {
+    /// Returns the value corresponding to the key.
+    fn lookup(&self) -> Option<&'m V>;
+}
+
+impl<'m, 'k, 'r, M: ?Sized + Map<Q>, Q: ?Sized> ViaKeyDeref<'m, M::Value>
+    for &KeyProbe<'m, 'k, M, &'r Q>
+{
+    fn lookup(&self) -> Option<&'m M::Value> {
+        self.map.lookup(*self.key)
+    }
+}
+
+/// Returns `true` if both maps have the same keys and equal values for them.
+pub fn maps_eq<L, R, K>(left: &L, right: &R) -> bool
+where
+    L: Entries<Key = K> + Map<K>,
+    R: Entries<Key = K> + Map<K>,
+    L::Value: PartialEq<R::Value>,
+{
+    left.all_entries(&mut |key, value| match right.lookup(key) {
+        Some(other) => *value == *other,
+        None => false,
+    }) && right.all_entries(&mut |key, _| left.lookup(key).is_some())
+}
+
+/// Key-wise difference between two maps.
+#[derive(Debug)]
+pub struct MapDiff<'a, L, R> {
+    left: &'a L,
+    right: &'a R,
+}
+
+/// Returns the key-wise difference between two maps.
+pub fn map_diff<'a, L, R>(left: &'a L, right: &'a R) -> MapDiff<'a, L, R> {
+    MapDiff { left, right }
+}
+
+/// Writer for the comma-separated report lines.
+struct Lines<'f, 'a> {
+    f: &'f mut fmt::Formatter<'a>,
+    started: bool,
+}
+
+impl<'f, 'a> Lines<'f, 'a> {
+    /// Starts a new report line and returns the formatter to write it into.
+    fn next(&mut self) -> Result<&mut fmt::Formatter<'a>, fmt::Error> {
+        if self.started {
+            self.f.write_str(",")?;
+        }
+        self.started = true;
+        self.f.write_str("\n    ")?;
+        Ok(self.f)
+    }
+}
+
+/// Writes `label: [key, ..]` line for the `map` keys, which are absent in the `other` map.
+fn fmt_absent_keys<A, B>(lines: &mut Lines<'_, '_>, label: &str, map: &A, other: &B) -> fmt::Result
+where
+    A: Entries,
+    A::Key: fmt::Debug,
+    B: Map<A::Key>,
+{
+    let mut result = Ok(());
+    let mut is_empty = true;
+    let _ = map.all_entries(&mut |key, _| {
+        if other.lookup(key).is_some() {
+            return true;
+        }
+        result = if is_empty {
+            is_empty = false;
+            lines
+                .next()
+                .and_then(|f| write!(f, "{}: [{:?}", label, key))
+        } else {
+            write!(lines.f, ", {:?}", key)
+        };
+        result.is_ok()
+    });
+    result?;
+    if !is_empty {
+        lines.f.write_str("]")?;
+    }
+    Ok(())
+}
+
+impl<'a, L, R, K> fmt::Display for MapDiff<'a, L, R>
+where
+    L: Entries<Key = K> + Map<K>,
+    R: Entries<Key = K> + Map<K>,
+    K: fmt::Debug,
+    L::Value: PartialEq<R::Value> + fmt::Debug,
+    R::Value: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut lines = Lines { f, started: false };
+        fmt_absent_keys(&mut lines, "missing keys", self.right, self.left)?;
+        fmt_absent_keys(&mut lines, "extra keys", self.left, self.right)?;
+
+        let right = self.right;
+        let mut result = Ok(());
+        let _ = self.left.all_entries(&mut |key, value| {
+            if let Some(other) = right.lookup(key) {
+                if *value != *other {
+                    result = lines.next().and_then(|f| {
+                        write!(
+                            f,
+                            "different values for key `{:?}`: left `{:?}`, right `{:?}`",
+                            key, value, other
+                        )
+                    });
+                }
+            }
+            result.is_ok()
+        });
+        result
+    }
+}
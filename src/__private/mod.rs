@@ -0,0 +1,96 @@
+//! Implementation details of the exported macros.
+//!
+//! Items in this module are not a part of the public API and can change at any time.
+
+mod bits;
+mod bytes;
+mod close;
+mod downcast;
+#[cfg(rustc_1_63)]
+mod failure;
+mod fields;
+mod integers;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "alloc")]
+mod maps;
+#[cfg(feature = "power-assert")]
+mod power;
+mod predicates;
+#[macro_use]
+mod sequences;
+mod sets;
+#[cfg(feature = "alloc")]
+mod unique;
+
+pub use self::bits::{bits_clear, bits_eq, bits_set, Bits, BitsReport};
+pub use self::bytes::{bytes_diff, BytesDiff, MAX_REPORTED_ROWS};
+pub use self::close::{all_close, AllClose, Elements, Numeric, WORST_ELEMENTS};
+pub use self::downcast::{actual, downcast, is, Actual, Downcast, Is};
+#[cfg(rustc_1_63)]
+pub use self::failure::{fail, fail_cmp, fail_eq, fail_ne};
+#[cfg(all(rustc_1_63, feature = "std"))]
+pub use self::failure::{violate, violate_cmp, violate_eq, violate_ne};
+pub use self::fields::{
+    all_match, as_field, EqField, Field, FieldProbe, FieldReport, MatcherField,
+};
+pub use self::integers::{Address, Integer, Operand, Operands};
+#[cfg(feature = "std")]
+pub use self::io::IoErrorDetails;
+#[cfg(feature = "alloc")]
+pub use self::maps::{map_diff, maps_eq, Entries, KeyProbe, Map, MapDiff};
+#[cfg(feature = "power-assert")]
+pub use self::power::{Diagram, Primitive, Probe, Recorder, Value};
+pub use self::predicates::{all, any, none, Predicate, MAX_REPORTED_ELEMENTS};
+pub use self::sequences::{
+    patterns, prefix, subsequence, suffix, Expected, Patterns, Sequence, Values,
+};
+pub use self::sets::{set_diff, Contains, Filtered, Items, Members, Scan, SetDiff, SetLookup};
+#[cfg(feature = "alloc")]
+pub use self::unique::{keys, Duplicates, Keys};
+#[cfg(feature = "power-assert")]
+pub use claim_macros::claim;
+pub use core::mem::discriminant;
+
+/// Traits, which are glob-imported by `assert_fields!` to resolve field expectations.
+pub mod fields_dispatch {
+    pub use super::fields::{ViaEq, ViaMatcher};
+}
+
+/// Traits, which are glob-imported by `assert_all_close!` to iterate over its arguments.
+pub mod close_dispatch {
+    pub use super::close::{ViaClone, ViaRef};
+}
+
+/// Traits, which are glob-imported by the overflow assertions to describe the operands.
+pub mod integers_dispatch {
+    pub use super::integers::{ViaDebug, ViaOpaque};
+}
+
+/// Traits, which are glob-imported by `assert_contains_key!` to look up the key.
+#[cfg(feature = "alloc")]
+pub mod maps_dispatch {
+    pub use super::maps::{ViaKey, ViaKeyDeref};
+}
+
+/// Traits, which are glob-imported by the set assertions to iterate over and look up elements.
+pub mod sets_dispatch {
+    pub use super::sets::{ViaIntoIter, ViaIterClone, ViaScan, ViaSet};
+}
+
+/// Traits, which are glob-imported by the sequence and predicate assertions to iterate over elements.
+pub mod items_dispatch {
+    pub use super::sets::{ViaIntoIter, ViaIterClone};
+}
+
+/// Traits, which are glob-imported by the uniqueness assertions to group element keys.
+#[cfg(feature = "alloc")]
+pub mod unique_dispatch {
+    pub use super::unique::{ViaHash, ViaOrd};
+}
+
+/// Traits, which are glob-imported by `claim!` to describe the recorded values.
+#[cfg(feature = "power-assert")]
+pub mod power_dispatch {
+    pub use super::power::{ViaDebug, ViaOpaque, ViaPrimitive};
+}
@@ -0,0 +1,220 @@
+use core::fmt;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Values of the `claim!` sub-expressions, recorded with their columns in the expression source.
+#[derive(Debug, Default)]
+pub struct Recorder {
+    values: Vec<(usize, Value)>,
+}
+
+impl Recorder {
+    /// Creates an empty recorder.
+    pub fn new() -> Self {
+        Recorder::default()
+    }
+
+    /// Records the sub-expression value, if it has one to show.
+    pub fn record(&mut self, column: usize, value: Option<Value>) {
+        if let Some(value) = value {
+            self.values.push((column, value));
+        }
+    }
+
+    /// Returns the diagram, which is printed under the expression source shifted by `indent`.
+    pub fn diagram(&self, indent: usize) -> Diagram<'_> {
+        Diagram {
+            values: &self.values,
+            indent,
+        }
+    }
+}
+
+/// Recorded value of the sub-expression.
+///
+/// Values of the primitive types are copied and formatted only if the assertion fails.
+/// Other values are formatted when they are recorded, as they can be moved
+/// or mutably borrowed by the rest of the expression.
+pub enum Value {
+    /// Boolean value.
+    Bool(bool),
+    /// Character value.
+    Char(char),
+    /// Signed integer of any size.
+    Signed(i128),
+    /// Unsigned integer of any size.
+    Unsigned(u128),
+    /// Single precision float, which is formatted differently from `f64`.
+    F32(f32),
+    /// Double precision float.
+    F64(f64),
+    /// Value, which was formatted when it was recorded.
+    Formatted(String),
+}
+
+impl fmt::Debug for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Bool(value) => fmt::Debug::fmt(value, f),
+            Value::Char(value) => fmt::Debug::fmt(value, f),
+            Value::Signed(value) => fmt::Debug::fmt(value, f),
+            Value::Unsigned(value) => fmt::Debug::fmt(value, f),
+            Value::F32(value) => fmt::Debug::fmt(value, f),
+            Value::F64(value) => fmt::Debug::fmt(value, f),
+            Value::Formatted(value) => f.write_str(value),
+        }
+    }
+}
+
+/// Primitive types, which values are recorded without formatting.
+pub trait Primitive: Copy {
+    /// Returns the recorded value.
+    fn value(self) -> Value;
+}
+
+macro_rules! impl_primitive {
+    ($($variant:ident: $($ty:ty),+;)+) => {
+        $($(
+            impl Primitive for $ty {
+                fn value(self) -> Value {
+                    Value::$variant(self.into())
+                }
+            }
+        )+)+
+    };
+}
+
+impl_primitive! {
+    Bool: bool;
+    Char: char;
+    Signed: i8, i16, i32, i64, i128;
+    Unsigned: u8, u16, u32, u64, u128;
+    F32: f32;
+    F64: f64;
+}
+
+impl Primitive for isize {
+    fn value(self) -> Value {
+        Value::Signed(self as i128)
+    }
+}
+
+impl Primitive for usize {
+    fn value(self) -> Value {
+        Value::Unsigned(self as u128)
+    }
+}
+
+/// Sub-expression value, which is described if it implements `Debug`.
+#[derive(Debug)]
+pub struct Probe<'a, T: ?Sized>(pub &'a T);
+
+/// Copies the values of primitive types.
+pub trait ViaPrimitive {
+    /// Returns the recorded value.
+    fn describe(&self) -> Option<Value>;
+}
+
+impl<'a, 'b, 'c, T: Primitive> ViaPrimitive for &'c &'b Probe<'a, T> {
+    fn describe(&self) -> Option<Value> {
+        Some(self.0.value())
+    }
+}
+
+/// Describes the value with its `Debug` implementation.
+pub trait ViaDebug {
+    /// Returns the recorded value.
+    fn describe(&self) -> Option<Value>;
+}
+
+impl<'a, 'b, T: ?Sized + fmt::Debug> ViaDebug for &'b Probe<'a, T> {
+    fn describe(&self) -> Option<Value> {
+        Some(Value::Formatted(format!("{:?}", self.0)))
+    }
+}
+
+/// Skips values, which do not implement `Debug`.
+pub trait ViaOpaque {
+    /// Returns `None`, as the value cannot be described.
+    fn describe(&self) -> Option<Value>;
+}
+
+impl<'a, T: ?Sized> ViaOpaque for Probe<'a, T> {
+    fn describe(&self) -> Option<Value> {
+        None
+    }
+}
+
+/// Power-assert diagram with the recorded values under their sub-expressions.
+#[derive(Debug)]
+pub struct Diagram<'a> {
+    values: &'a [(usize, Value)],
+    indent: usize,
+}
+
+impl<'a> Diagram<'a> {
+    fn write_line(&self, f: &mut fmt::Formatter<'_>, line: &[char]) -> fmt::Result {
+        write!(f, "\n{:indent$}", "", indent = self.indent)?;
+        let len = line.iter().rposition(|c| *c != ' ').map_or(0, |i| i + 1);
+        for c in &line[..len] {
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for Diagram<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Sub-expression can be evaluated more than once, only the last value is shown.
+        let mut values: Vec<(usize, String)> = Vec::new();
+        for value in self.values.iter().rev() {
+            if values.iter().all(|other| other.0 != value.0) {
+                values.push((value.0, format!("{:?}", value.1)));
+            }
+        }
+        values.sort_by_key(|value| value.0);
+        let mut pending: Vec<&(usize, String)> = values.iter().collect();
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let width = pending
+            .iter()
+            .map(|(column, value)| column + value.chars().count())
+            .max()
+            .unwrap_or(0);
+        let pipes = |pending: &[&(usize, String)]| {
+            let mut line = alloc::vec![' '; width];
+            for (column, _) in pending {
+                line[*column] = '|';
+            }
+            line
+        };
+        self.write_line(f, &pipes(&pending))?;
+
+        // Values are placed from the right, each one is printed on the first line,
+        // where it does not overlap with the values and pipes to the right of it.
+        while !pending.is_empty() {
+            let mut line = pipes(&pending);
+            let mut limit = usize::MAX;
+            let mut remaining = Vec::new();
+            for value in pending.iter().rev() {
+                let (column, text) = (value.0, &value.1);
+                if column + text.chars().count() < limit {
+                    for (offset, c) in text.chars().enumerate() {
+                        line[column + offset] = c;
+                    }
+                } else {
+                    remaining.push(*value);
+                }
+                limit = column;
+            }
+            self.write_line(f, &line)?;
+            remaining.reverse();
+            pending = remaining;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use core::fmt;
+
+/// How many of the failed elements are reported by the predicate assertions.
+pub const MAX_REPORTED_ELEMENTS: usize = 10;
+
+/// Amount of elements, which should satisfy the predicate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Quantifier {
+    All,
+    Any,
+    None,
+}
+
+/// Result of checking the predicate for every element.
+#[derive(Debug)]
+pub struct Predicate<I, F> {
+    items: I,
+    predicate: F,
+    quantifier: Quantifier,
+    total: usize,
+    failed: usize,
+}
+
+impl<I, F> Predicate<I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(I::Item) -> bool,
+{
+    fn new(items: I, predicate: F, quantifier: Quantifier) -> Self {
+        let mut report = Predicate {
+            items,
+            predicate,
+            quantifier,
+            total: 0,
+            failed: 0,
+        };
+        for item in report.items.clone() {
+            report.total += 1;
+            if report.is_failed(item) {
+                report.failed += 1;
+            }
+        }
+        report
+    }
+
+    /// Returns `true` if the element should be reported.
+    fn is_failed(&self, item: I::Item) -> bool {
+        let satisfied = (self.predicate)(item);
+        match self.quantifier {
+            Quantifier::All | Quantifier::Any => !satisfied,
+            Quantifier::None => satisfied,
+        }
+    }
+
+    /// Returns `true` if the expected amount of elements satisfied the predicate.
+    pub fn is_ok(&self) -> bool {
+        match self.quantifier {
+            Quantifier::All | Quantifier::None => self.failed == 0,
+            Quantifier::Any => self.failed < self.total,
+        }
+    }
+}
+
+/// Checks that all elements satisfy the `predicate`.
+pub fn all<I, F>(items: I, predicate: F) -> Predicate<I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(I::Item) -> bool,
+{
+    Predicate::new(items, predicate, Quantifier::All)
+}
+
+/// Checks that at least one element satisfies the `predicate`.
+pub fn any<I, F>(items: I, predicate: F) -> Predicate<I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(I::Item) -> bool,
+{
+    Predicate::new(items, predicate, Quantifier::Any)
+}
+
+/// Checks that no elements satisfy the `predicate`.
+pub fn none<I, F>(items: I, predicate: F) -> Predicate<I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(I::Item) -> bool,
+{
+    Predicate::new(items, predicate, Quantifier::None)
+}
+
+impl<I, F> fmt::Display for Predicate<I, F>
+where
+    I: Iterator + Clone,
+    I::Item: fmt::Debug,
+    F: Fn(I::Item) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.quantifier {
+            Quantifier::All | Quantifier::Any => "failed",
+            Quantifier::None => "matched",
+        };
+        write!(
+            f,
+            "\n    {} elements: {} of {}",
+            label, self.failed, self.total
+        )?;
+
+        let mut reported = 0;
+        // Elements are moved into the predicate, so the second copy is used for the report.
+        let items = self.items.clone().zip(self.items.clone()).enumerate();
+        for (index, (item, value)) in items {
+            if reported == MAX_REPORTED_ELEMENTS {
+                break;
+            }
+            if self.is_failed(item) {
+                write!(f, ",\n    [{}]: `{:?}`", index, value)?;
+                reported += 1;
+            }
+        }
+        if self.failed > reported {
+            write!(f, ",\n    ... and {} more", self.failed - reported)?;
+        }
+        Ok(())
+    }
+}
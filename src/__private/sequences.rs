@@ -0,0 +1,323 @@
+use core::fmt;
+use core::ops::Range;
+
+/// Expected elements of the sequence assertions.
+pub trait Expected<T> {
+    /// Expected element.
+    type Element;
+    /// Iterator over the expected elements.
+    type Elements: Iterator<Item = Self::Element>;
+
+    /// Returns amount of the expected elements.
+    fn count(&self) -> usize;
+
+    /// Returns the expected elements in order.
+    fn elements(&self) -> Self::Elements;
+
+    /// Returns `true` if `item` matches the expected `element`.
+    fn matches(&self, element: &Self::Element, item: &T) -> bool;
+
+    /// Writes the expected `element`.
+    fn fmt_element(&self, element: &Self::Element, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Expected elements compared with [`PartialEq`].
+#[derive(Debug)]
+pub struct Values<J>(pub J);
+
+impl<T, J> Expected<T> for Values<J>
+where
+    J: Iterator + Clone,
+    J::Item: fmt::Debug,
+    T: PartialEq<J::Item>,
+{
+    type Element = J::Item;
+    type Elements = J;
+
+    fn count(&self) -> usize {
+        self.0.clone().count()
+    }
+
+    fn elements(&self) -> J {
+        self.0.clone()
+    }
+
+    fn matches(&self, element: &J::Item, item: &T) -> bool {
+        *item == *element
+    }
+
+    fn fmt_element(&self, element: &J::Item, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{:?}`", element)
+    }
+}
+
+/// Expected elements matched against patterns.
+#[derive(Debug)]
+pub struct Patterns<F> {
+    names: &'static [&'static str],
+    matcher: F,
+}
+
+/// Creates patterns for elements of the `items` iterator.
+///
+/// `matcher` is called with the pattern index and the element.
+pub fn patterns<I, F>(_items: &I, names: &'static [&'static str], matcher: F) -> Patterns<F>
+where
+    I: Iterator,
+    F: Fn(usize, &I::Item) -> bool,
+{
+    Patterns { names, matcher }
+}
+
+impl<T, F: Fn(usize, &T) -> bool> Expected<T> for Patterns<F> {
+    type Element = usize;
+    type Elements = Range<usize>;
+
+    fn count(&self) -> usize {
+        self.names.len()
+    }
+
+    fn elements(&self) -> Range<usize> {
+        0..self.names.len()
+    }
+
+    fn matches(&self, index: &usize, item: &T) -> bool {
+        (self.matcher)(*index, item)
+    }
+
+    fn fmt_element(&self, index: &usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`", self.names[*index])
+    }
+}
+
+/// Place, where the sequence stopped matching.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Stop {
+    /// All expected elements were matched.
+    Nowhere,
+    /// Element at the index is different from the expected one.
+    Mismatch(usize),
+    /// Sequence ended at the index.
+    End(usize),
+    /// Sequence has less elements than expected.
+    TooShort(usize),
+    /// Sequence ended without a match, last element was matched at the index.
+    NotFound(Option<usize>),
+}
+
+/// Result of the sequence matching.
+#[derive(Debug)]
+pub struct Sequence<'e, I, E> {
+    actual: I,
+    expected: &'e E,
+    matched: usize,
+    stop: Stop,
+}
+
+impl<'e, I, E> Sequence<'e, I, E> {
+    /// Returns `true` if all expected elements were matched.
+    pub fn is_ok(&self) -> bool {
+        self.stop == Stop::Nowhere
+    }
+}
+
+/// Matches expected elements one by one, starting from the `offset` index of the `actual` sequence.
+fn from_offset<I, E>(actual: I, expected: &E, offset: usize) -> Sequence<'_, I, E>
+where
+    I: Iterator + Clone,
+    E: Expected<I::Item>,
+{
+    let mut items = actual.clone().skip(offset);
+    let mut matched = 0;
+    let mut stop = Stop::Nowhere;
+    for element in expected.elements() {
+        let index = offset + matched;
+        match items.next() {
+            Some(ref item) if expected.matches(&element, item) => matched += 1,
+            Some(_) => {
+                stop = Stop::Mismatch(index);
+                break;
+            }
+            None => {
+                stop = Stop::End(index);
+                break;
+            }
+        }
+    }
+    Sequence {
+        actual,
+        expected,
+        matched,
+        stop,
+    }
+}
+
+/// Checks that `actual` sequence starts with the `expected` elements.
+pub fn prefix<I, E>(actual: I, expected: &E) -> Sequence<'_, I, E>
+where
+    I: Iterator + Clone,
+    E: Expected<I::Item>,
+{
+    from_offset(actual, expected, 0)
+}
+
+/// Checks that `actual` sequence ends with the `expected` elements.
+pub fn suffix<I, E>(actual: I, expected: &E) -> Sequence<'_, I, E>
+where
+    I: Iterator + Clone,
+    E: Expected<I::Item>,
+{
+    let actual_len = actual.clone().count();
+    match actual_len.checked_sub(expected.count()) {
+        Some(offset) => from_offset(actual, expected, offset),
+        None => Sequence {
+            actual,
+            expected,
+            matched: 0,
+            stop: Stop::TooShort(actual_len),
+        },
+    }
+}
+
+/// Checks that `actual` sequence contains the `expected` elements in the same order,
+/// possibly with other elements between them.
+pub fn subsequence<I, E>(actual: I, expected: &E) -> Sequence<'_, I, E>
+where
+    I: Iterator + Clone,
+    E: Expected<I::Item>,
+{
+    let mut elements = expected.elements();
+    let mut next = elements.next();
+    let mut matched = 0;
+    let mut last = None;
+    for (index, item) in actual.clone().enumerate() {
+        let found = match next {
+            Some(ref element) => expected.matches(element, &item),
+            None => break,
+        };
+        if found {
+            matched += 1;
+            last = Some(index);
+            next = elements.next();
+        }
+    }
+    let stop = if next.is_none() {
+        Stop::Nowhere
+    } else {
+        Stop::NotFound(last)
+    };
+    Sequence {
+        actual,
+        expected,
+        matched,
+        stop,
+    }
+}
+
+impl<'e, I, E> Sequence<'e, I, E>
+where
+    I: Iterator,
+    E: Expected<I::Item>,
+{
+    /// Writes the first expected element, which was not matched.
+    fn fmt_expected(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.expected.elements().nth(self.matched) {
+            Some(element) => self.expected.fmt_element(&element, f),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'e, I, E> fmt::Display for Sequence<'e, I, E>
+where
+    I: Iterator + Clone,
+    I::Item: fmt::Debug,
+    E: Expected<I::Item>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\n    matched: {} of {} expected elements,\n    ",
+            self.matched,
+            self.expected.count()
+        )?;
+        match self.stop {
+            Stop::Nowhere => Ok(()),
+            Stop::Mismatch(index) => {
+                write!(f, "stopped at index {}: expected ", index)?;
+                self.fmt_expected(f)?;
+                match self.actual.clone().nth(index) {
+                    Some(item) => write!(f, ", got `{:?}`", item),
+                    None => Ok(()),
+                }
+            }
+            Stop::End(index) => {
+                write!(f, "stopped at index {}: expected ", index)?;
+                self.fmt_expected(f)?;
+                f.write_str(", got end of sequence")
+            }
+            Stop::TooShort(len) => write!(f, "sequence is too short: {} elements", len),
+            Stop::NotFound(last) => {
+                f.write_str("stopped at end of sequence: expected ")?;
+                self.fmt_expected(f)?;
+                match last {
+                    Some(index) => write!(f, " after index {}", index),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// Implementation of the sequence assertions.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_sequence {
+    (@fail $fail:tt @values $check:ident, $op:expr, $seq:expr, $expected:expr; $($arg:tt)*) => {
+        match (&$seq, &$expected) {
+            (seq_val, expected_val) => {
+                use $crate::__private::items_dispatch::*;
+
+                let expected = $crate::__private::Values((&$crate::__private::Items(expected_val)).items());
+                let result = $crate::__private::$check((&$crate::__private::Items(seq_val)).items(), &expected);
+                $crate::__assert_sequence!(@fail $fail @result $check, result, $op; $($arg)*);
+            }
+        }
+    };
+    (@fail $fail:tt @patterns $check:ident, $op:expr, $seq:expr, [$($pat:pat),+]; $($arg:tt)*) => {
+        match &$seq {
+            seq_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let items = (&$crate::__private::Items(seq_val)).items();
+                let expected = $crate::__private::patterns(&items, &[$(stringify!($pat)),+], |index, item| {
+                    $crate::__assert_sequence!(@pattern index, item, 0; $($pat),+)
+                });
+                let result = $crate::__private::$check(items, &expected);
+                $crate::__assert_sequence!(@fail $fail @result $check, result, $op; $($arg)*);
+            }
+        }
+    };
+    (@pattern $index:ident, $item:ident, $offset:expr; $pat:pat) => {{
+        #[allow(irrefutable_let_patterns)]
+        let matched = $index == $offset && if let $pat = $item { true } else { false };
+        matched
+    }};
+    (@pattern $index:ident, $item:ident, $offset:expr; $pat:pat, $($rest:pat),+) => {
+        if $index == $offset {
+            $crate::__assert_sequence!(@pattern $index, $item, $offset; $pat)
+        } else {
+            $crate::__assert_sequence!(@pattern $index, $item, $offset + 1; $($rest),+)
+        }
+    };
+    (@fail $fail:tt @result $check:ident, $result:ident, $op:expr;) => {
+        if !$result.is_ok() {
+            $crate::__fail!($fail, concat!("assert_", stringify!($check)), concat!("assertion failed: `(left ", $op, " right)`{}"), $result);
+        }
+    };
+    (@fail $fail:tt @result $check:ident, $result:ident, $op:expr; $($arg:tt)+) => {
+        if !$result.is_ok() {
+            $crate::__fail!($fail, concat!("assert_", stringify!($check)), concat!("assertion failed: `(left ", $op, " right)`{}: {}"), $result, format_args!($($arg)+));
+        }
+    };
+}
@@ -0,0 +1,221 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeSet;
+#[cfg(feature = "alloc")]
+use core::borrow::Borrow;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+/// Collection or iterator of the set assertions arguments.
+#[derive(Debug)]
+pub struct Items<'a, T: ?Sized>(pub &'a T);
+
+/// Iterates over collection elements by reference.
+pub trait ViaIntoIter<'a, T: ?Sized> {
+    /// Elements iterator.
+    type Iter: Iterator + Clone;
+
+    /// Returns iterator over elements.
+    fn items(&self) -> Self::Iter;
+}
+
+impl<'a, T: ?Sized> ViaIntoIter<'a, T> for Items<'a, T>
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::IntoIter: Clone,
+{
+    type Iter = <&'a T as IntoIterator>::IntoIter;
+
+    fn items(&self) -> Self::Iter {
+        self.0.into_iter()
+    }
+}
+
+/// Iterates over a copy of the iterator.
+pub trait ViaIterClone<'a, T> {
+    /// Elements iterator.
+    type Iter: Iterator + Clone;
+
+    /// Returns iterator over elements.
+    fn items(&self) -> Self::Iter;
+}
+
+impl<'a, T: Iterator + Clone> ViaIterClone<'a, T> for &Items<'a, T> {
+    type Iter = T;
+
+    fn items(&self) -> Self::Iter {
+        self.0.clone()
+    }
+}
+
+/// Collection, which is checked for the elements membership.
+#[derive(Debug)]
+pub struct Members<'a, T: ?Sized>(pub &'a T);
+
+/// Checks membership with the set lookup.
+pub trait ViaSet<'a, T: ?Sized> {
+    /// Returns the set itself, elements iterator is not needed.
+    fn members<I>(&self, items: I) -> SetLookup<'a, T>;
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, K: Ord> ViaSet<'a, BTreeSet<K>> for Members<'a, BTreeSet<K>> {
+    fn members<I>(&self, _items: I) -> SetLookup<'a, BTreeSet<K>> {
+        SetLookup(self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, K: Hash + Eq, S: BuildHasher> ViaSet<'a, HashSet<K, S>> for Members<'a, HashSet<K, S>> {
+    fn members<I>(&self, _items: I) -> SetLookup<'a, HashSet<K, S>> {
+        SetLookup(self.0)
+    }
+}
+
+/// Checks membership by scanning over all elements.
+pub trait ViaScan {
+    /// Returns the scanner over the `items`.
+    fn members<I: Iterator + Clone>(&self, items: I) -> Scan<I>;
+}
+
+impl<'a, T: ?Sized> ViaScan for &Members<'a, T> {
+    fn members<I: Iterator + Clone>(&self, items: I) -> Scan<I> {
+        Scan(items)
+    }
+}
+
+/// Collection, which can tell if it contains an element of `Q` type.
+pub trait Contains<Q> {
+    /// Returns `true` if the collection contains the `item`.
+    fn contains(&self, item: &Q) -> bool;
+}
+
+/// Membership check with the set lookup.
+#[derive(Debug)]
+pub struct SetLookup<'a, T: ?Sized>(&'a T);
+
+#[cfg(feature = "alloc")]
+impl<'a, K: Ord, Q: Borrow<K>> Contains<Q> for SetLookup<'a, BTreeSet<K>> {
+    fn contains(&self, item: &Q) -> bool {
+        self.0.contains(item.borrow())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, K, Q, S> Contains<Q> for SetLookup<'a, HashSet<K, S>>
+where
+    K: Hash + Eq,
+    Q: Borrow<K>,
+    S: BuildHasher,
+{
+    fn contains(&self, item: &Q) -> bool {
+        self.0.contains(item.borrow())
+    }
+}
+
+/// Membership check by scanning over all elements.
+#[derive(Debug)]
+pub struct Scan<I>(I);
+
+impl<I: Iterator + Clone, Q: PartialEq<I::Item>> Contains<Q> for Scan<I> {
+    fn contains(&self, item: &Q) -> bool {
+        self.0.clone().any(|other| *item == other)
+    }
+}
+
+/// Elements, which are either contained or not contained in the `members`.
+///
+/// `Display` implementation prints them as a list.
+#[derive(Debug)]
+pub struct Filtered<I, M> {
+    items: I,
+    members: M,
+    contained: bool,
+}
+
+impl<I, M> Filtered<I, M>
+where
+    I: Iterator + Clone,
+    M: Contains<I::Item>,
+{
+    /// Selects `items`, which are contained in the `members`.
+    pub fn contained(items: I, members: M) -> Self {
+        Filtered {
+            items,
+            members,
+            contained: true,
+        }
+    }
+
+    /// Selects `items`, which are not contained in the `members`.
+    pub fn not_contained(items: I, members: M) -> Self {
+        Filtered {
+            items,
+            members,
+            contained: false,
+        }
+    }
+
+    /// Returns `true` if no elements were selected.
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = I::Item> + '_ {
+        let members = &self.members;
+        let contained = self.contained;
+        self.items
+            .clone()
+            .filter(move |item| members.contains(item) == contained)
+    }
+}
+
+impl<I, M> fmt::Display for Filtered<I, M>
+where
+    I: Iterator + Clone,
+    I::Item: fmt::Debug,
+    M: Contains<I::Item>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Elements, which are missing in one of the compared sets.
+#[derive(Debug)]
+pub struct SetDiff<'a, M, E> {
+    missing: &'a M,
+    extra: &'a E,
+}
+
+/// Returns the difference with `missing` (right-only) and `extra` (left-only) elements.
+pub fn set_diff<'a, M, E>(missing: &'a M, extra: &'a E) -> SetDiff<'a, M, E> {
+    SetDiff { missing, extra }
+}
+
+impl<'a, I, M, J, N> fmt::Display for SetDiff<'a, Filtered<I, M>, Filtered<J, N>>
+where
+    I: Iterator + Clone,
+    I::Item: fmt::Debug,
+    M: Contains<I::Item>,
+    J: Iterator + Clone,
+    J::Item: fmt::Debug,
+    N: Contains<J::Item>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let has_missing = !self.missing.is_empty();
+        if has_missing {
+            write!(f, "\n    missing elements: {}", self.missing)?;
+        }
+        if !self.extra.is_empty() {
+            if has_missing {
+                f.write_str(",")?;
+            }
+            write!(f, "\n    extra elements: {}", self.extra)?;
+        }
+        Ok(())
+    }
+}
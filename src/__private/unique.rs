@@ -0,0 +1,121 @@
+use core::fmt;
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Elements with the function returning their keys.
+#[derive(Debug)]
+pub struct Keys<I, F> {
+    items: I,
+    key: F,
+}
+
+/// Creates keys for elements of the `items` iterator.
+pub fn keys<I, F, K>(items: I, key: F) -> Keys<I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(I::Item) -> K,
+{
+    Keys { items, key }
+}
+
+// Keys are grouped with the auto-ref method lookup:
+// `(&keys).duplicates()` uses `HashMap` if keys are hashable and `std` feature is enabled,
+// and falls back to `BTreeMap` for ordered keys.
+
+/// Groups hashable keys.
+pub trait ViaHash {
+    /// Key type.
+    type Key;
+
+    /// Returns keys, which were found more than once.
+    fn duplicates(&self) -> Duplicates<Self::Key>;
+}
+
+#[cfg(feature = "std")]
+impl<I, F, K> ViaHash for Keys<I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(I::Item) -> K,
+    K: Hash + Eq,
+{
+    type Key = K;
+
+    fn duplicates(&self) -> Duplicates<K> {
+        let mut indices = HashMap::new();
+        for (index, item) in self.items.clone().enumerate() {
+            indices
+                .entry((self.key)(item))
+                .or_insert_with(Vec::new)
+                .push(index);
+        }
+        Duplicates::new(indices)
+    }
+}
+
+/// Groups ordered keys.
+pub trait ViaOrd {
+    /// Key type.
+    type Key;
+
+    /// Returns keys, which were found more than once.
+    fn duplicates(&self) -> Duplicates<Self::Key>;
+}
+
+impl<I, F, K> ViaOrd for &Keys<I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(I::Item) -> K,
+    K: Ord,
+{
+    type Key = K;
+
+    fn duplicates(&self) -> Duplicates<K> {
+        let mut indices = BTreeMap::new();
+        for (index, item) in self.items.clone().enumerate() {
+            indices
+                .entry((self.key)(item))
+                .or_insert_with(Vec::new)
+                .push(index);
+        }
+        Duplicates::new(indices)
+    }
+}
+
+/// Keys, which were found more than once, with all indices of their elements.
+#[derive(Debug)]
+pub struct Duplicates<K> {
+    groups: Vec<(K, Vec<usize>)>,
+}
+
+impl<K> Duplicates<K> {
+    fn new<G: IntoIterator<Item = (K, Vec<usize>)>>(groups: G) -> Self {
+        let mut groups = groups
+            .into_iter()
+            .filter(|(_, indices)| indices.len() > 1)
+            .collect::<Vec<_>>();
+        groups.sort_by_key(|(_, indices)| indices[0]);
+        Duplicates { groups }
+    }
+
+    /// Returns `true` if all keys are unique.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+}
+
+impl<K: fmt::Debug> fmt::Display for Duplicates<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (key, indices)) in self.groups.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "\n    `{:?}` at indices {:?}", key, indices)?;
+        }
+        Ok(())
+    }
+}
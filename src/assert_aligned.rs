@@ -0,0 +1,138 @@
+/// Asserts that the pointer or the integer address is aligned to the power of two.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Address can be a reference, a raw pointer or an unsigned integer.
+/// Alignment is a `usize`, same to the [`align_of`] result, and it should be a power of two.
+///
+/// On failure, the address is reported in hex with its offset from the alignment.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_aligned!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let value: u64 = 42;
+///
+/// assert_aligned!(&value, std::mem::align_of::<u64>());
+/// assert_aligned!(0x1000usize, 4096);
+///
+/// // With custom messages
+/// assert_aligned!(&value as *const u64, 8, "Expecting that {:p} is aligned", &value);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// assert_aligned!(0x1004usize, 8);  // Will panic
+/// # }
+/// ```
+///
+/// [`align_of`]: https://doc.rust-lang.org/core/mem/fn.align_of.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_aligned!`]: ./macro.debug_assert_aligned.html
+#[macro_export]
+macro_rules! assert_aligned {
+    (@fail $fail:tt $address:expr, $align:expr,) => {
+        $crate::assert_aligned!(@fail $fail $address, $align);
+    };
+    (@fail $fail:tt $address:expr, $align:expr) => {
+        match (&$address, &$align) {
+            (address, align) => {
+                let align: usize = *align;
+                let address = $crate::__private::Address::address(address);
+                if !align.is_power_of_two() {
+                    $crate::__fail!($fail, "assert_aligned", "assertion failed, expected alignment to be a power of two, got `{}`", align);
+                }
+                if address % align as u128 != 0 {
+                    $crate::__fail!($fail, "assert_aligned", "assertion failed, expected address aligned to `{}`, got `{:#x}` with offset `{}`", align, address, address % align as u128);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $address:expr, $align:expr, $($arg:tt)+) => {
+        match (&$address, &$align) {
+            (address, align) => {
+                let align: usize = *align;
+                let address = $crate::__private::Address::address(address);
+                if !align.is_power_of_two() {
+                    $crate::__fail!($fail, "assert_aligned", "assertion failed, expected alignment to be a power of two, got `{}`: {}", align, format_args!($($arg)+));
+                }
+                if address % align as u128 != 0 {
+                    $crate::__fail!($fail, "assert_aligned", "assertion failed, expected address aligned to `{}`, got `{:#x}` with offset `{}`: {}", align, address, address % align as u128, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_aligned!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that the pointer or the integer address is aligned to the power of two in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_aligned!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_aligned!`]: ./macro.assert_aligned.html
+#[macro_export]
+macro_rules! debug_assert_aligned {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_aligned!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn aligned() {
+        let value = 42u64;
+        let mut array = [0u32; 4];
+        assert_aligned!(&value, core::mem::align_of::<u64>());
+        assert_aligned!(&mut array[1], 4,);
+        let pointer: *const [u32] = &array[..];
+        assert_aligned!(pointer, 4, "custom {}", "message");
+        assert_aligned!(0x1000u64, 4096);
+        debug_assert_aligned!(0u8, 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected address aligned to `8`, got `0x1004` with offset `4`"
+    )]
+    fn default_panic_message() {
+        assert_aligned!(0x1004usize, 8);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected address aligned to `16`, got `0x21` with offset `1`: custom message"
+    )]
+    fn custom_panic_message() {
+        assert_aligned!(0x21u128 as *const u8, 16, "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected alignment to be a power of two, got `3`")]
+    fn invalid_alignment() {
+        assert_aligned!(0usize, 3);
+    }
+}
@@ -0,0 +1,144 @@
+/// Asserts that all elements satisfy the predicate.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Elements can be provided as an array, slice, collection or a `Clone` iterator.
+/// Predicate is called with each element, same to the [`Iterator::all`] closure.
+///
+/// On failure, indexes and values of the failed elements are reported,
+/// up to ten of them.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_all!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let ports = vec![80, 443, 8080];
+///
+/// assert_all!(ports, |port| *port > 0);
+///
+/// // With custom messages
+/// assert_all!(ports, |port| *port > 0, "Invalid ports in {:?}", ports);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let ports = vec![80, 443, 8080];
+///
+/// assert_all!(ports, |port| *port < 1024);  // Will panic
+/// # }
+/// ```
+///
+/// [`Iterator::all`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.all
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_all!`]: ./macro.debug_assert_all.html
+#[macro_export]
+macro_rules! assert_all {
+    (@fail $fail:tt $items:expr, $predicate:expr,) => {
+        $crate::assert_all!(@fail $fail $items, $predicate);
+    };
+    (@fail $fail:tt $items:expr, $predicate:expr) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let result = $crate::__private::all((&$crate::__private::Items(items_val)).items(), $predicate);
+                if !result.is_ok() {
+                    $crate::__fail!($fail, "assert_all", r#"assertion failed: `(all elements satisfy predicate)`{}"#, result);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $items:expr, $predicate:expr, $($arg:tt)+) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let result = $crate::__private::all((&$crate::__private::Items(items_val)).items(), $predicate);
+                if !result.is_ok() {
+                    $crate::__fail!($fail, "assert_all", r#"assertion failed: `(all elements satisfy predicate)`{}: {}"#, result, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_all!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that all elements satisfy the predicate in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_all!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_all!`]: ./macro.assert_all.html
+#[macro_export]
+macro_rules! debug_assert_all {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_all!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_all() {
+        assert_all!([1, 2, 3], |x| *x > 0);
+        assert_all!([0; 0], |x| *x > 0,);
+        assert_all!((1..4), |x| x > 0, "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(all elements satisfy predicate)`
+    failed elements: 2 of 4,
+    [1]: `-2`,
+    [3]: `-4`"#)]
+    fn default_panic_message() {
+        assert_all!([1, -2, 3, -4], |x| *x > 0);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(all elements satisfy predicate)`
+    failed elements: 1 of 1,
+    [0]: `-1`: checking -1"#)]
+    fn custom_panic_message() {
+        assert_all!([-1], |x| *x > 0, "checking {}", -1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(all elements satisfy predicate)`
+    failed elements: 12 of 12,
+    [0]: `0`,
+    [1]: `1`,
+    [2]: `2`,
+    [3]: `3`,
+    [4]: `4`,
+    [5]: `5`,
+    [6]: `6`,
+    [7]: `7`,
+    [8]: `8`,
+    [9]: `9`,
+    ... and 2 more"#)]
+    fn reports_limited_elements() {
+        assert_all!((0..12), |x| x > 100);
+    }
+}
@@ -0,0 +1,229 @@
+/// Asserts that elements of both expressions are pairwise approximately equal.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Both expressions can be arrays, slices, collections or `Clone` iterators
+/// of `f32`, `f64` or integer types. Elements are compared as `f64` values,
+/// so 64-bit and pointer-sized integers beyond `2^53` are rounded before the comparison.
+/// Elements are close if `|left - right| <= max(rel * max(|left|, |right|), abs)`.
+/// Infinite elements are close only to the infinity of the same sign, and NaN is never close.
+///
+/// Nested collections, such as `Vec<Vec<f64>>`, are not supported,
+/// but they can be compared as flattened iterators, ex. `rows.iter().flatten()`.
+///
+/// Relative tolerance can be provided with `rel = ..` argument and defaults to
+/// `1e-5` for `f32`, `1e-9` for `f64` and zero for integer elements.
+/// If the sides have different element types, the larger default tolerance of both is used.
+/// Absolute tolerance can be provided with `abs = ..` argument and defaults to zero.
+///
+/// On failure, the amount of mismatched elements and up to five most different ones are reported.
+/// Sequences of different lengths are reported as a shape mismatch.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_all_close!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let computed = vec![0.1 + 0.2, 1.0 / 3.0];
+///
+/// assert_all_close!(computed, [0.3, 0.333_333_333_333]);
+/// assert_all_close!(computed, [0.3, 0.33], abs = 0.01);
+/// assert_all_close!(computed[..], [0.3, 0.3], rel = 0.2, abs = 1e-9);
+/// assert_all_close!(computed.iter().map(|x| x * 2.0), [0.6, 0.666_666_666_666]);
+///
+/// // With custom messages
+/// assert_all_close!(computed, [0.3, 0.3], rel = 0.2, "Comparing {:?}", computed);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// assert_all_close!([1.0, 2.0, 3.0], [1.0, 2.5, 3.0]);  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_all_close!`]: ./macro.debug_assert_all_close.html
+#[macro_export]
+macro_rules! assert_all_close {
+    (@fail $fail:tt @options ($left:expr, $right:expr, $rel:expr, $abs:expr) rel = $value:expr $(,)*) => {
+        $crate::assert_all_close!(@fail $fail @check ($left, $right, Some(f64::from($value)), $abs));
+    };
+    (@fail $fail:tt @options ($left:expr, $right:expr, $rel:expr, $abs:expr) rel = $value:expr, $($rest:tt)+) => {
+        $crate::assert_all_close!(@fail $fail @options ($left, $right, Some(f64::from($value)), $abs) $($rest)+);
+    };
+    (@fail $fail:tt @options ($left:expr, $right:expr, $rel:expr, $abs:expr) abs = $value:expr $(,)*) => {
+        $crate::assert_all_close!(@fail $fail @check ($left, $right, $rel, Some(f64::from($value))));
+    };
+    (@fail $fail:tt @options ($left:expr, $right:expr, $rel:expr, $abs:expr) abs = $value:expr, $($rest:tt)+) => {
+        $crate::assert_all_close!(@fail $fail @options ($left, $right, $rel, Some(f64::from($value))) $($rest)+);
+    };
+    (@fail $fail:tt @options ($left:expr, $right:expr, $rel:expr, $abs:expr) $($arg:tt)+) => {
+        $crate::assert_all_close!(@fail $fail @check ($left, $right, $rel, $abs) $($arg)+);
+    };
+    (@fail $fail:tt @check ($left:expr, $right:expr, $rel:expr, $abs:expr) $($arg:tt)*) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                use $crate::__private::close_dispatch::*;
+
+                let result = $crate::__private::all_close(
+                    (&$crate::__private::Elements(left_val)).elements(),
+                    (&$crate::__private::Elements(right_val)).elements(),
+                    $rel,
+                    $abs,
+                );
+                if !result.is_ok() {
+                    $crate::assert_all_close!(@fail $fail @panic result $($arg)*);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt @panic $result:ident) => {
+        $crate::__fail!($fail, "assert_all_close", r#"assertion failed: `(left all close to right)`{}"#, $result)
+    };
+    (@fail $fail:tt @panic $result:ident $($arg:tt)+) => {
+        $crate::__fail!($fail, "assert_all_close", r#"assertion failed: `(left all close to right)`{}: {}"#, $result, format_args!($($arg)+))
+    };
+    (@fail $fail:tt $left:expr, $right:expr $(,)*) => {
+        $crate::assert_all_close!(@fail $fail @check ($left, $right, None, None));
+    };
+    (@fail $fail:tt $left:expr, $right:expr, $($rest:tt)+) => {
+        $crate::assert_all_close!(@fail $fail @options ($left, $right, None, None) $($rest)+);
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_all_close!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that elements of both expressions are pairwise approximately equal in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_all_close!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_all_close!`]: ./macro.assert_all_close.html
+#[macro_export]
+macro_rules! debug_assert_all_close {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_all_close!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_close_values() {
+        assert_all_close!([1.0_f32, 2.0], [1.000_001_f32, 2.0]);
+        assert_all_close!([1.0, 2.0], [1.5, 2.0], abs = 0.5,);
+        assert_all_close!([1, 2, 3], [1.0, 2.0, 3.0]);
+        assert_all_close!([100.0], [101.0], rel = 0.01, "custom {}", "message");
+        assert_all_close!([0.0; 0], [0.0; 0]);
+        assert_all_close!([1_i64, -2], [1.0, -2.0]);
+        assert_all_close!([1_usize, 2], [1_u64, 2]);
+        assert_all_close!([-1_isize], [-1_i64]);
+    }
+
+    #[test]
+    fn accepts_flattened_rows() {
+        let rows = [[1.0, 2.0], [3.0, 4.0]];
+        assert_all_close!(rows.iter().flatten(), [1.0, 2.0, 3.0, 4.000_000_000_1]);
+    }
+
+    #[test]
+    fn uses_less_precise_default_tolerance() {
+        assert_all_close!([1.0_f64], [1.000_001_f32]);
+        assert_all_close!([1.000_001_f32], [1.0_f64]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left all close to right)`
+    mismatched elements: 2 of 4,
+    tolerance: rel = 1e-9, abs = 0.0,
+    [3]: left = 4.0, right = 5.0, diff = 1.0,
+    [1]: left = 2.0, right = 2.5, diff = 0.5"#)]
+    fn default_panic_message() {
+        assert_all_close!([1.0, 2.0, 3.0, 4.0], [1.0, 2.5, 3.0, 5.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left all close to right)`
+    mismatched elements: 1 of 2,
+    tolerance: rel = 0.1, abs = 0.5,
+    [0]: left = NaN, right = 1.0, diff = NaN: checking NaN"#)]
+    fn custom_panic_message() {
+        let zero = 0.0;
+        let nan = zero / zero;
+        assert_all_close!(
+            [nan, 1.0],
+            [1.0, 1.0],
+            abs = 0.5,
+            rel = 0.1,
+            "checking {:?}",
+            nan
+        );
+    }
+
+    #[test]
+    fn accepts_equal_infinities() {
+        assert_all_close!(
+            [f64::INFINITY, -f64::INFINITY],
+            [f64::INFINITY, -f64::INFINITY]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"[0]: left = inf, right = 1.0, diff = inf"#)]
+    fn infinity_is_not_close_to_finite() {
+        assert_all_close!([f64::INFINITY], [1.0], rel = 1.0, abs = 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"[0]: left = inf, right = -inf, diff = inf"#)]
+    fn opposite_infinities_are_not_close() {
+        assert_all_close!([f64::INFINITY], [-f64::INFINITY]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"[0]: left = NaN, right = NaN, diff = NaN"#)]
+    fn nan_is_not_close_to_nan() {
+        let zero = 0.0;
+        assert_all_close!([zero / zero], [zero / zero], abs = 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left all close to right)`
+    shape mismatch: left has 2 elements, right has 3 elements"#)]
+    fn shape_mismatch_panic_message() {
+        assert_all_close!([1.0, 2.0], [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left all close to right)`
+    mismatched elements: 7 of 7,
+    tolerance: rel = 0.0, abs = 0.0,
+    [1]: left = 0.0, right = 7.0, diff = 7.0,
+    [3]: left = 0.0, right = 6.0, diff = 6.0,
+    [5]: left = 0.0, right = 5.0, diff = 5.0,
+    [6]: left = 0.0, right = 4.0, diff = 4.0,
+    [4]: left = 0.0, right = 3.0, diff = 3.0"#)]
+    fn reports_only_worst_elements() {
+        assert_all_close!([0, 0, 0, 0, 0, 0, 0], [1, 7, 2, 6, 3, 5, 4]);
+    }
+}
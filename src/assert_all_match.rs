@@ -0,0 +1,166 @@
+/// Asserts that all elements match any of the given variants.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Elements can be provided as an array, slice, collection or a `Clone` iterator.
+/// Patterns and guards are the same to the [`assert_matches!`] ones.
+///
+/// On failure, indexes and values of the mismatched elements are reported,
+/// up to ten of them.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_all_match!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let responses: Vec<Result<u16, &str>> = vec![Ok(200), Ok(204)];
+///
+/// assert_all_match!(responses, Ok(200..=299));
+///
+/// // With custom messages
+/// assert_all_match!(responses, Ok(code) if *code < 300, "Unexpected responses {:?}", responses);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let responses: Vec<Result<u16, &str>> = vec![Ok(200), Ok(204)];
+///
+/// assert_all_match!(responses, Ok(200) | Err(_));  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_all_match!`]: ./macro.debug_assert_all_match.html
+#[macro_export]
+macro_rules! assert_all_match {
+    (@fail $fail:tt $items:expr, $( $pattern:pat )|+) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let result = $crate::__private::all((&$crate::__private::Items(items_val)).items(), |item| match item {
+                    $( $pattern )|+ => true,
+                    _ => false,
+                });
+                if !result.is_ok() {
+                    $crate::__fail!($fail, "assert_all_match", r#"assertion failed: `(all elements match pattern)`
+    pattern: {},{}"#, stringify!($($pattern) |+), result);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $items:expr, $( $pattern:pat )|+ if $guard:expr) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let result = $crate::__private::all((&$crate::__private::Items(items_val)).items(), |item| match item {
+                    $( $pattern )|+ if $guard => true,
+                    _ => false,
+                });
+                if !result.is_ok() {
+                    $crate::__fail!($fail, "assert_all_match", r#"assertion failed: `(all elements match pattern)`
+    pattern: {},{}"#, stringify!($($pattern) |+ if $guard), result);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $items:expr, $( $pattern:pat )|+, $($arg:tt)+) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let result = $crate::__private::all((&$crate::__private::Items(items_val)).items(), |item| match item {
+                    $( $pattern )|+ => true,
+                    _ => false,
+                });
+                if !result.is_ok() {
+                    $crate::__fail!($fail, "assert_all_match", r#"assertion failed: `(all elements match pattern)`
+    pattern: {},{}: {}"#, stringify!($($pattern) |+), result, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $items:expr, $( $pattern:pat )|+ if $guard:expr, $($arg:tt)+) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let result = $crate::__private::all((&$crate::__private::Items(items_val)).items(), |item| match item {
+                    $( $pattern )|+ if $guard => true,
+                    _ => false,
+                });
+                if !result.is_ok() {
+                    $crate::__fail!($fail, "assert_all_match", r#"assertion failed: `(all elements match pattern)`
+    pattern: {},{}: {}"#, stringify!($($pattern) |+ if $guard), result, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_all_match!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that all elements match any of the given variants in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_all_match!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_all_match!`]: ./macro.assert_all_match.html
+#[macro_export]
+macro_rules! debug_assert_all_match {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_all_match!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_all_matches() {
+        assert_all_match!([Some(1), Some(2)], Some(_));
+        assert_all_match!([Some(1), None], Some(1) | None);
+        assert_all_match!([Some(1), Some(2)], Some(x) if *x > 0);
+        assert_all_match!([1, 2], 1..=2, "custom {}", "message");
+        assert_all_match!([1, 2], x if *x < 3, "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(all elements match pattern)`
+    pattern: Some(_),
+    failed elements: 1 of 3,
+    [1]: `None`"#)]
+    fn default_panic_message() {
+        assert_all_match!([Some(1), None, Some(2)], Some(_));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(all elements match pattern)`
+    pattern: Some(x) if *x > 1,
+    failed elements: 1 of 2,
+    [0]: `Some(1)`: checking 1"#)]
+    fn custom_panic_message() {
+        assert_all_match!([Some(1), Some(2)], Some(x) if *x > 1, "checking {}", 1);
+    }
+}
@@ -0,0 +1,125 @@
+/// Asserts that at least one element satisfies the predicate.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Elements can be provided as an array, slice, collection or a `Clone` iterator.
+/// Predicate is called with each element, same to the [`Iterator::any`] closure.
+///
+/// On failure, indexes and values of all elements are reported,
+/// up to ten of them.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_any!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let ports = vec![80, 443, 8080];
+///
+/// assert_any!(ports, |port| *port == 443);
+///
+/// // With custom messages
+/// assert_any!(ports, |port| *port == 443, "No HTTPS port in {:?}", ports);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let ports = vec![80, 443, 8080];
+///
+/// assert_any!(ports, |port| *port == 22);  // Will panic
+/// # }
+/// ```
+///
+/// [`Iterator::any`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.any
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_any!`]: ./macro.debug_assert_any.html
+#[macro_export]
+macro_rules! assert_any {
+    (@fail $fail:tt $items:expr, $predicate:expr,) => {
+        $crate::assert_any!(@fail $fail $items, $predicate);
+    };
+    (@fail $fail:tt $items:expr, $predicate:expr) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let result = $crate::__private::any((&$crate::__private::Items(items_val)).items(), $predicate);
+                if !result.is_ok() {
+                    $crate::__fail!($fail, "assert_any", r#"assertion failed: `(any element satisfies predicate)`{}"#, result);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $items:expr, $predicate:expr, $($arg:tt)+) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let result = $crate::__private::any((&$crate::__private::Items(items_val)).items(), $predicate);
+                if !result.is_ok() {
+                    $crate::__fail!($fail, "assert_any", r#"assertion failed: `(any element satisfies predicate)`{}: {}"#, result, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_any!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that at least one element satisfies the predicate in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_any!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_any!`]: ./macro.assert_any.html
+#[macro_export]
+macro_rules! debug_assert_any {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_any!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_any() {
+        assert_any!([1, 2, 3], |x| *x == 2);
+        assert_any!([1], |x| *x > 0,);
+        assert_any!((1..4), |x| x > 2, "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(any element satisfies predicate)`
+    failed elements: 2 of 2,
+    [0]: `1`,
+    [1]: `2`"#)]
+    fn default_panic_message() {
+        assert_any!([1, 2], |x| *x > 2);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(any element satisfies predicate)`
+    failed elements: 0 of 0: checking empty"#)]
+    fn custom_panic_message() {
+        assert_any!([0; 0], |x| *x > 0, "checking {}", "empty");
+    }
+}
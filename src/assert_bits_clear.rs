@@ -0,0 +1,122 @@
+/// Asserts that all bits of the mask are clear in the value.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Values and the mask can be of any primitive integer type, which should be the same for all of them.
+///
+/// On failure, the values and the mask are reported in binary and hex,
+/// and the bits of the mask, which are set in the value, are marked under the binary digits.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_bits_clear!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let flags: u8 = 0b0101;
+///
+/// assert_bits_clear!(flags, 0b1010);
+///
+/// // With custom messages
+/// assert_bits_clear!(flags, 0x08, "Checking flags {:#x}", flags);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let flags: u8 = 0b0101;
+///
+/// assert_bits_clear!(flags, 0b0100);  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_bits_clear!`]: ./macro.debug_assert_bits_clear.html
+#[macro_export]
+macro_rules! assert_bits_clear {
+    (@fail $fail:tt $value:expr, $mask:expr,) => {
+        $crate::assert_bits_clear!(@fail $fail $value, $mask);
+    };
+    (@fail $fail:tt $value:expr, $mask:expr) => {
+        match (&$value, &$mask) {
+            (value, mask) => {
+                let report = $crate::__private::bits_clear(*value, *mask);
+                if !report.is_ok() {
+                    $crate::__fail!($fail, "assert_bits_clear", r#"assertion failed: `(value & mask == 0)`{}"#, report);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $value:expr, $mask:expr, $($arg:tt)+) => {
+        match (&$value, &$mask) {
+            (value, mask) => {
+                let report = $crate::__private::bits_clear(*value, *mask);
+                if !report.is_ok() {
+                    $crate::__fail!($fail, "assert_bits_clear", r#"assertion failed: `(value & mask == 0)`{}: {}"#, report, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_bits_clear!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that all bits of the mask are clear in the value in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_bits_clear!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_bits_clear!`]: ./macro.assert_bits_clear.html
+#[macro_export]
+macro_rules! debug_assert_bits_clear {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_bits_clear!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn clear() {
+        assert_bits_clear!(0b1010u8, 0b0101);
+        assert_bits_clear!(0i32, -1,);
+        assert_bits_clear!(1u64 << 63, 1, "custom {}", "message");
+        debug_assert_bits_clear!(0isize, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `(value & mask == 0)`
+    value:    `0b11111110` (`0xfe`),
+    mask:     `0b10000011` (`0x83`)
+                 ^     ^")]
+    fn default_panic_message() {
+        assert_bits_clear!(-2i8, -125);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `(value & mask == 0)`
+    value:    `0b00000001` (`0x01`),
+    mask:     `0b00000001` (`0x01`)
+                        ^: custom message")]
+    fn custom_panic_message() {
+        assert_bits_clear!(1u8, 1, "custom {}", "message");
+    }
+}
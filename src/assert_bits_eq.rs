@@ -0,0 +1,124 @@
+/// Asserts that the bits of the mask are the same in the value and the expected value.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Values and the mask can be of any primitive integer type, which should be the same for all of them.
+///
+/// On failure, the values and the mask are reported in binary and hex,
+/// and the bits of the mask, which differ in the values, are marked under the binary digits.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_bits_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let flags: u8 = 0b0101;
+///
+/// assert_bits_eq!(flags, 0b1101, 0b0111);
+///
+/// // With custom messages
+/// assert_bits_eq!(flags, 0x05, 0x0f, "Checking flags {:#x}", flags);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let flags: u8 = 0b0101;
+///
+/// assert_bits_eq!(flags, 0b0001, 0b0111);  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_bits_eq!`]: ./macro.debug_assert_bits_eq.html
+#[macro_export]
+macro_rules! assert_bits_eq {
+    (@fail $fail:tt $value:expr, $expected:expr, $mask:expr,) => {
+        $crate::assert_bits_eq!(@fail $fail $value, $expected, $mask);
+    };
+    (@fail $fail:tt $value:expr, $expected:expr, $mask:expr) => {
+        match (&$value, &$expected, &$mask) {
+            (value, expected, mask) => {
+                let report = $crate::__private::bits_eq(*value, *expected, *mask);
+                if !report.is_ok() {
+                    $crate::__fail!($fail, "assert_bits_eq", r#"assertion failed: `(value & mask == expected & mask)`{}"#, report);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $value:expr, $expected:expr, $mask:expr, $($arg:tt)+) => {
+        match (&$value, &$expected, &$mask) {
+            (value, expected, mask) => {
+                let report = $crate::__private::bits_eq(*value, *expected, *mask);
+                if !report.is_ok() {
+                    $crate::__fail!($fail, "assert_bits_eq", r#"assertion failed: `(value & mask == expected & mask)`{}: {}"#, report, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_bits_eq!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that the bits of the mask are the same in the value and the expected value in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_bits_eq!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_bits_eq!`]: ./macro.assert_bits_eq.html
+#[macro_export]
+macro_rules! debug_assert_bits_eq {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_bits_eq!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn equal() {
+        assert_bits_eq!(0b1101u8, 0b0101, 0b0111);
+        assert_bits_eq!(0xdead_beefu32, 0xffff_beef, 0xffff,);
+        assert_bits_eq!(-1i16, 0x7fff, 0x7fff, "custom {}", "message");
+        debug_assert_bits_eq!(1u8, 0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `(value & mask == expected & mask)`
+    value:    `0b00001101` (`0x0d`),
+    expected: `0b00000011` (`0x03`),
+    mask:     `0b00000111` (`0x07`)
+                      ^^")]
+    fn default_panic_message() {
+        assert_bits_eq!(0b1101u8, 0b0011, 0b0111);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `(value & mask == expected & mask)`
+    value:    `0b00000000` (`0x00`),
+    expected: `0b11111111` (`0xff`),
+    mask:     `0b10000000` (`0x80`)
+                 ^: custom message")]
+    fn custom_panic_message() {
+        assert_bits_eq!(0u8, 0xff, 0x80, "custom {}", "message");
+    }
+}
@@ -0,0 +1,127 @@
+/// Asserts that all bits of the mask are set in the value.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Values and the mask can be of any primitive integer type, which should be the same for all of them.
+///
+/// On failure, the values and the mask are reported in binary and hex,
+/// and the bits of the mask, which are clear in the value, are marked under the binary digits.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_bits_set!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let flags: u8 = 0b0101;
+///
+/// assert_bits_set!(flags, 0b0101);
+///
+/// // With custom messages
+/// assert_bits_set!(flags, 0x04, "Checking flags {:#x}", flags);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let flags: u8 = 0b0101;
+///
+/// assert_bits_set!(flags, 0b1000);  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_bits_set!`]: ./macro.debug_assert_bits_set.html
+#[macro_export]
+macro_rules! assert_bits_set {
+    (@fail $fail:tt $value:expr, $mask:expr,) => {
+        $crate::assert_bits_set!(@fail $fail $value, $mask);
+    };
+    (@fail $fail:tt $value:expr, $mask:expr) => {
+        match (&$value, &$mask) {
+            (value, mask) => {
+                let report = $crate::__private::bits_set(*value, *mask);
+                if !report.is_ok() {
+                    $crate::__fail!($fail, "assert_bits_set", r#"assertion failed: `(value & mask == mask)`{}"#, report);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $value:expr, $mask:expr, $($arg:tt)+) => {
+        match (&$value, &$mask) {
+            (value, mask) => {
+                let report = $crate::__private::bits_set(*value, *mask);
+                if !report.is_ok() {
+                    $crate::__fail!($fail, "assert_bits_set", r#"assertion failed: `(value & mask == mask)`{}: {}"#, report, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_bits_set!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that all bits of the mask are set in the value in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_bits_set!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_bits_set!`]: ./macro.assert_bits_set.html
+#[macro_export]
+macro_rules! debug_assert_bits_set {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_bits_set!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn set() {
+        assert_bits_set!(0b1101u8, 0b0101);
+        assert_bits_set!(-1i64, 1 << 63,);
+        assert_bits_set!(
+            0x8000_0000_0000_0000_0000_0000_0000_0001u128,
+            1,
+            "custom {}",
+            "message"
+        );
+        debug_assert_bits_set!(7usize, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `(value & mask == mask)`
+    value:    `0b00000101` (`0x05`),
+    mask:     `0b00001110` (`0x0e`)
+                     ^ ^")]
+    fn default_panic_message() {
+        assert_bits_set!(0b0101u8, 0b1110);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `(value & mask == mask)`
+    value:    `0b0000000000000000` (`0x0000`),
+    mask:     `0b1000000000000000` (`0x8000`)
+                 ^: custom message")]
+    fn custom_panic_message() {
+        assert_bits_set!(0u16, 0x8000, "custom {}", "message");
+    }
+}
@@ -0,0 +1,152 @@
+/// Asserts that two values have the same bytes.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Both values can be of any type, which implements `AsRef<[u8]>`,
+/// such as `Vec<u8>`, `&[u8]`, byte arrays and strings.
+///
+/// On failure, the offset of the first different byte and the lengths of both values are reported,
+/// along with a side-by-side hexdump with ASCII columns, where the different bytes are marked.
+/// Rows without differences, which are not next to the different ones, are skipped,
+/// and up to sixteen rows with differences are reported.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_bytes_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let encoded = vec![0x48, 0x65, 0x6c, 0x6c, 0x6f];
+///
+/// assert_bytes_eq!(encoded, b"Hello");
+///
+/// // With custom messages
+/// assert_bytes_eq!(encoded, "Hello", "Checking {:?}", encoded);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let encoded = vec![0x48, 0x65, 0x6c, 0x6c, 0x6f];
+///
+/// assert_bytes_eq!(encoded, b"Hello!");  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_bytes_eq!`]: ./macro.debug_assert_bytes_eq.html
+#[macro_export]
+macro_rules! assert_bytes_eq {
+    (@fail $fail:tt $left:expr, $right:expr,) => {
+        $crate::assert_bytes_eq!(@fail $fail $left, $right);
+    };
+    (@fail $fail:tt $left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let diff = $crate::__private::bytes_diff(left_val, right_val);
+                if !diff.is_ok() {
+                    $crate::__fail!($fail, "assert_bytes_eq", r#"assertion failed: `(left == right)`{}"#, diff);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let diff = $crate::__private::bytes_diff(left_val, right_val);
+                if !diff.is_ok() {
+                    $crate::__fail!($fail, "assert_bytes_eq", r#"assertion failed: `(left == right)`{}: {}"#, diff, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_bytes_eq!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that two values have the same bytes in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_bytes_eq!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_bytes_eq!`]: ./macro.assert_bytes_eq.html
+#[macro_export]
+macro_rules! debug_assert_bytes_eq {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_bytes_eq!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn equal() {
+        assert_bytes_eq!([1u8, 2, 3], [1u8, 2, 3]);
+        assert_bytes_eq!(&b"abc"[..], "abc",);
+        assert_bytes_eq!([0u8; 0], "", "custom {}", "message");
+        debug_assert_bytes_eq!(b"abc", "abc");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `(left == right)`
+    first difference at offset 5 (0x5), left: 12 bytes, right: 12 bytes
+    00000000  48 65 6c 6c 6f 2c 20 77  |Hello, w|  48 65 6c 6c 6f 2e 20 77  |Hello. w|
+                             ^^              ^                    ^^              ^
+    00000008  6f 72 6c 64              |orld    |  6f 72 6c 64              |orld    |")]
+    fn default_panic_message() {
+        assert_bytes_eq!(b"Hello, world", b"Hello. world");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `(left == right)`
+    first difference at offset 3 (0x3), left: 3 bytes, right: 5 bytes
+    00000000  00 01 02                 |...     |  00 01 02 ff 0a           |.....   |
+                       ^^ ^^               ^^               ^^ ^^               ^^: custom message")]
+    fn custom_panic_message() {
+        assert_bytes_eq!(
+            &[0u8, 1, 2][..],
+            [0u8, 1, 2, 255, 10],
+            "custom {}",
+            "message"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `(left == right)`
+    first difference at offset 32 (0x20), left: 64 bytes, right: 64 bytes
+    ...
+    00000018  00 00 00 00 00 00 00 00  |........|  00 00 00 00 00 00 00 00  |........|
+    00000020  00 00 00 00 00 00 00 00  |........|  01 00 00 00 00 00 00 00  |........|
+              ^^                        ^          ^^                        ^
+    00000028  00 00 00 00 00 00 00 00  |........|  00 00 00 00 00 00 00 00  |........|
+    ...")]
+    fn skips_equal_rows() {
+        let mut right = [0u8; 64];
+        right[32] = 1;
+        assert_bytes_eq!([0u8; 64], right);
+    }
+
+    #[test]
+    #[should_panic(expected = "    ... and 4 more rows with differences")]
+    fn reports_limited_rows() {
+        assert_bytes_eq!([0u8; 160], [1u8; 160]);
+    }
+}
@@ -0,0 +1,340 @@
+/// Asserts that the comparison of expressions holds, dispatching on the operator.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Accepts any of the `<`, `<=`, `>`, `>=`, `==` and `!=` operators,
+/// so it can be used instead of the [`assert_lt!`], [`assert_le!`], [`assert_gt!`],
+/// [`assert_ge!`], [`assert_eq!`] and [`assert_ne!`] macros.
+/// Comparisons can be chained, e.g. `assert_cmp!(0 <= x < 10)`,
+/// in which case every comparison is checked from left to right.
+///
+/// Every expression is evaluated only once,
+/// and the first failed comparison is reported with its `left` and `right` values.
+///
+/// Expressions are split at the comparison operators, so expressions
+/// with the `&&` or `||` operators, or with generic arguments other than `::<..>`,
+/// should be wrapped in parentheses.
+/// The same applies to the `&`, `|` and `^` operators, which bind tighter than comparisons
+/// in Rust, so `x & 1 == 1` is rejected instead of being read as `(x & 1) == 1`:
+///
+/// ```rust,compile_fail
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let x = 5;
+/// assert_cmp!(x & 1 == 1);  // Will not compile, use `(x & 1) == 1` instead
+/// # }
+/// ```
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_cmp!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let x: i32 = 5;
+/// assert_cmp!(x < 10);
+/// assert_cmp!(0 <= x < 10);
+/// assert_cmp!(x.pow(2) != 20);
+///
+/// // With custom messages
+/// assert_cmp!(x >= 1, "Expecting that {} is positive", x);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let x = 10;
+/// assert_cmp!(0 <= x < 10);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_lt!`]: ./macro.assert_lt.html
+/// [`assert_le!`]: ./macro.assert_le.html
+/// [`assert_gt!`]: ./macro.assert_gt.html
+/// [`assert_ge!`]: ./macro.assert_ge.html
+/// [`assert_eq!`]: https://doc.rust-lang.org/std/macro.assert_eq.html
+/// [`assert_ne!`]: https://doc.rust-lang.org/std/macro.assert_ne.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_cmp!`]: ./macro.debug_assert_cmp.html
+#[macro_export]
+macro_rules! assert_cmp {
+    (@fail $fail:tt $($rest:tt)+) => {
+        $crate::__assert_cmp!(@fail $fail @operand [] [] $($rest)+)
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_cmp!(@fail [] $($arg)*)
+    };
+}
+
+/// Implementation of the `assert_cmp!` macro.
+///
+/// Tokens are munched into the `[operand] operator [operand] ...` list,
+/// and then every comparison is expanded into a nested match, which evaluates the right operand.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_cmp {
+    // Generic arguments are copied as is, until their brackets are balanced.
+    (@fail $fail:tt @generic [$($done:tt)*] [$($operand:tt)*] [$open:tt] > $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @operand [$($done)*] [$($operand)* >] $($rest)*)
+    };
+    (@fail $fail:tt @generic [$($done:tt)*] [$($operand:tt)*] [$open:tt $($opens:tt)+] > $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @generic [$($done)*] [$($operand)* >] [$($opens)+] $($rest)*)
+    };
+    (@fail $fail:tt @generic [$($done:tt)*] [$($operand:tt)*] [$first:tt $second:tt] >> $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @operand [$($done)*] [$($operand)* >>] $($rest)*)
+    };
+    (@fail $fail:tt @generic [$($done:tt)*] [$($operand:tt)*] [$first:tt $second:tt $($opens:tt)+] >> $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @generic [$($done)*] [$($operand)* >>] [$($opens)+] $($rest)*)
+    };
+    (@fail $fail:tt @generic [$($done:tt)*] [$($operand:tt)*] [$($opens:tt)+] < $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @generic [$($done)*] [$($operand)* <] [< $($opens)+] $($rest)*)
+    };
+    (@fail $fail:tt @generic [$($done:tt)*] [$($operand:tt)*] [$($opens:tt)+] $token:tt $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @generic [$($done)*] [$($operand)* $token] [$($opens)+] $($rest)*)
+    };
+    (@fail $fail:tt @generic [$($done:tt)*] [$($operand:tt)*] [$($opens:tt)+]) => {
+        compile_error!("unbalanced generic arguments in `assert_cmp!`")
+    };
+
+    (@fail $fail:tt @operand [$($done:tt)*] [] < $($rest:tt)*) => {
+        // Qualified path, such as `<T as Trait>::CONST`.
+        $crate::__assert_cmp!(@fail $fail @generic [$($done)*] [<] [<] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)*] :: < $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @generic [$($done)*] [$($operand)* :: <] [<] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)+] < $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @operand [$($done)* [$($operand)+] <] [] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)+] <= $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @operand [$($done)* [$($operand)+] <=] [] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)+] > $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @operand [$($done)* [$($operand)+] >] [] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)+] >= $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @operand [$($done)* [$($operand)+] >=] [] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)+] == $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @operand [$($done)* [$($operand)+] ==] [] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)+] != $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @operand [$($done)* [$($operand)+] !=] [] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)*] + $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @unary [$($done)*] [$($operand)* +] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)*] - $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @unary [$($done)*] [$($operand)* -] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)*] * $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @unary [$($done)*] [$($operand)* *] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)*] / $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @unary [$($done)*] [$($operand)* /] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)*] % $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @unary [$($done)*] [$($operand)* %] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)*] << $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @unary [$($done)*] [$($operand)* <<] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)*] >> $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @unary [$($done)*] [$($operand)* >>] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)*] ! $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @unary [$($done)*] [$($operand)* !] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)*] as $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @unary [$($done)*] [$($operand)* as] $($rest)*)
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)+] & $($rest:tt)*) => {
+        compile_error!("`&` is not supported by `assert_cmp!`, wrap the operand in parentheses")
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)+] | $($rest:tt)*) => {
+        compile_error!("`|` is not supported by `assert_cmp!`, wrap the operand in parentheses")
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)+] ^ $($rest:tt)*) => {
+        compile_error!("`^` is not supported by `assert_cmp!`, wrap the operand in parentheses")
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)+] && $($rest:tt)*) => {
+        compile_error!("`&&` is not supported by `assert_cmp!`, wrap the operand in parentheses")
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)+] || $($rest:tt)*) => {
+        compile_error!("`||` is not supported by `assert_cmp!`, wrap the operand in parentheses")
+    };
+    (@fail $fail:tt @operand [] [$($operand:tt)*]) => {
+        compile_error!("expected comparison operator in `assert_cmp!`")
+    };
+    (@fail $fail:tt @operand [] [$($operand:tt)*] , $($arg:tt)*) => {
+        compile_error!("expected comparison operator in `assert_cmp!`")
+    };
+    (@fail $fail:tt @operand [$($done:tt)+] []) => {
+        compile_error!("expected expression after comparison operator in `assert_cmp!`")
+    };
+    (@fail $fail:tt @operand [$($done:tt)+] [] , $($arg:tt)*) => {
+        compile_error!("expected expression after comparison operator in `assert_cmp!`")
+    };
+    (@fail $fail:tt @operand [$first:tt $($done:tt)+] [$($operand:tt)+]) => {
+        $crate::__assert_cmp!(@fail $fail @first [] $first $($done)+ [$($operand)+])
+    };
+    (@fail $fail:tt @operand [$first:tt $($done:tt)+] [$($operand:tt)+] ,) => {
+        $crate::__assert_cmp!(@fail $fail @first [] $first $($done)+ [$($operand)+])
+    };
+    (@fail $fail:tt @operand [$first:tt $($done:tt)+] [$($operand:tt)+] , $($arg:tt)+) => {
+        $crate::__assert_cmp!(@fail $fail @first [$($arg)+] $first $($done)+ [$($operand)+])
+    };
+    (@fail $fail:tt @operand [$($done:tt)*] [$($operand:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @operand [$($done)*] [$($operand)* $token] $($rest)*)
+    };
+
+    // After an operator, `&` and `&&` are references, not the bitwise and logical operators.
+    (@fail $fail:tt @unary [$($done:tt)*] [$($operand:tt)*] & $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @unary [$($done)*] [$($operand)* &] $($rest)*)
+    };
+    (@fail $fail:tt @unary [$($done:tt)*] [$($operand:tt)*] && $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @unary [$($done)*] [$($operand)* &&] $($rest)*)
+    };
+    (@fail $fail:tt @unary [$($done:tt)*] [$($operand:tt)*] $($rest:tt)*) => {
+        $crate::__assert_cmp!(@fail $fail @operand [$($done)*] [$($operand)*] $($rest)*)
+    };
+
+    (@fail $fail:tt @first [$($arg:tt)*] [$left:expr] $($rest:tt)+) => {
+        match &$left {
+            left_val => $crate::__assert_cmp!(@fail $fail @next [$($arg)*] [$left] left_val $($rest)+),
+        }
+    };
+    (@fail $fail:tt @next [$($arg:tt)*] [$left:expr] $left_val:ident $op:tt [$right:expr] $($rest:tt)*) => {
+        match &$right {
+            right_val => {
+                // The reborrows below are intentional. Without them, the stack slot for the
+                // borrow is initialized even before the values are compared, leading to a
+                // noticeable slow down.
+                if !(*$left_val $op *right_val) {
+                    $crate::__assert_cmp!(@fail $fail @panic [$($arg)*] $op, ($left, $right), &*$left_val, &*right_val);
+                }
+                $crate::__assert_cmp!(@fail $fail @next [$($arg)*] [$right] right_val $($rest)*)
+            }
+        }
+    };
+    (@fail $fail:tt @next [$($arg:tt)*] [$left:expr] $left_val:ident) => {
+        ()
+    };
+    (@fail $fail:tt @panic [] $op:tt, ($left:expr, $right:expr), $left_val:expr, $right_val:expr) => {
+        $crate::__fail_cmp!($fail, "assert_cmp", ($left, $right), $left_val, $right_val, concat!("assertion failed: `(left ", stringify!($op), r#" right)`
+    left: `{:?}`,
+    right: `{:?}`"#), $left_val, $right_val)
+    };
+    (@fail $fail:tt @panic [$($arg:tt)+] $op:tt, ($left:expr, $right:expr), $left_val:expr, $right_val:expr) => {
+        $crate::__fail_cmp!($fail, "assert_cmp", ($left, $right), $left_val, $right_val, concat!("assertion failed: `(left ", stringify!($op), r#" right)`
+    left: `{:?}`,
+    right: `{:?}`: {}"#), $left_val, $right_val, format_args!($($arg)+))
+    };
+}
+
+/// Asserts that the comparison of expressions holds in runtime.
+///
+/// Like [`assert_cmp!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_cmp!`]: ./macro.assert_cmp.html
+#[macro_export]
+macro_rules! debug_assert_cmp {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_cmp!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::cell::Cell;
+
+    #[test]
+    fn equal_and_ordered() {
+        assert_cmp!(1 < 2);
+        assert_cmp!(2 <= 2);
+        assert_cmp!(3 > 2);
+        assert_cmp!(2 >= 2);
+        assert_cmp!(2 == 2);
+        assert_cmp!(1 != 2);
+        assert_cmp!(1 + 1 < 2 * 2,);
+        assert_cmp!(1 < 2, "custom {}", "message");
+    }
+
+    #[test]
+    fn chained() {
+        let x = 5;
+        assert_cmp!(0 <= x < 10);
+        assert_cmp!(10 > x >= 5 == 5);
+        assert_cmp!(0 <= x < 10, "custom {}", "message");
+    }
+
+    #[test]
+    fn generic_arguments() {
+        let items = [1u8, 2, 3];
+        assert_cmp!(items.iter().map(|x| *x as u32).sum::<u32>() == 6);
+        assert_cmp!(<u8 as Default>::default() < items[0]);
+        assert_cmp!(core::mem::size_of::<Option<Option<u8>>>() <= 3);
+    }
+
+    #[test]
+    fn references_and_bit_operators() {
+        let x = 5;
+        assert_cmp!(&x == &5);
+        assert_cmp!(x - &1 == *&4 > -x);
+        assert_cmp!((x & 1) == 1 != (x ^ 1));
+        assert_cmp!((x | 2) == 7, "custom {}", "message");
+    }
+
+    #[test]
+    fn evaluates_once() {
+        let calls = Cell::new(0);
+        let next = || {
+            calls.set(calls.get() + 1);
+            calls.get()
+        };
+        assert_cmp!(0 < next() <= 1);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left < right)`
+    left: `10`,
+    right: `10`"#)]
+    fn default_panic_message() {
+        let x = 10;
+        assert_cmp!(0 <= x < 10);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left <= right)`
+    left: `0`,
+    right: `-1`: we are checking -1"#)]
+    fn custom_panic_message() {
+        let x = -1;
+        assert_cmp!(0 <= x < 10, "we are checking {}", x);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left != right)`
+    left: `2`,
+    right: `2`"#)]
+    fn not_equal_panic_message() {
+        assert_cmp!(1 + 1 != 2);
+    }
+}
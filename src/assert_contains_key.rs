@@ -0,0 +1,175 @@
+/// Asserts that map contains the given key.
+///
+/// This macro requires `alloc` feature for [`BTreeMap`] support
+/// and `std` feature for [`HashMap`] support.
+///
+/// Key can be of any type the map can be looked up by, ex. `&str` for `String` keys.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_contains_key!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use std::collections::BTreeMap;
+///
+/// # fn main() {
+/// let mut map = BTreeMap::new();
+/// map.insert("answer".to_string(), 42);
+///
+/// assert_contains_key!(map, "answer");
+///
+/// // With custom messages
+/// assert_contains_key!(map, "answer", "Looking for the answer in {:?}", map);
+/// # }
+/// ```
+///
+/// Reference to the value will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use std::collections::BTreeMap;
+///
+/// # fn main() {
+/// let mut map = BTreeMap::new();
+/// map.insert(1, "one");
+///
+/// let value = assert_contains_key!(map, 1);
+/// assert_eq!(*value, "one");
+/// # }
+/// ```
+///
+/// Missing key will cause panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// use std::collections::BTreeMap;
+///
+/// # fn main() {
+/// let map: BTreeMap<i32, i32> = BTreeMap::new();
+///
+/// assert_contains_key!(map, 1);  // Will panic
+/// # }
+/// ```
+///
+/// [`BTreeMap`]: https://doc.rust-lang.org/alloc/collections/btree_map/struct.BTreeMap.html
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_contains_key!`]: ./macro.debug_assert_contains_key.html
+#[macro_export]
+macro_rules! assert_contains_key {
+    (@fail $fail:tt $map:expr, $key:expr,) => {
+        $crate::assert_contains_key!(@fail $fail $map, $key)
+    };
+    (@fail $fail:tt $map:expr, $key:expr) => {
+        match (&$map, &$key) {
+            (map, key) => {
+                use $crate::__private::maps_dispatch::*;
+
+                match (&$crate::__private::KeyProbe::new(map, key)).lookup() {
+                    Some(value) => value,
+                    None => {
+                        $crate::__fail!($fail, "assert_contains_key", r#"assertion failed: `(map contains key)`
+    key: `{:?}`,
+    map: `{:?}`"#, &*key, &*map)
+                    }
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $map:expr, $key:expr, $($arg:tt)+) => {
+        match (&$map, &$key) {
+            (map, key) => {
+                use $crate::__private::maps_dispatch::*;
+
+                match (&$crate::__private::KeyProbe::new(map, key)).lookup() {
+                    Some(value) => value,
+                    None => {
+                        $crate::__fail!($fail, "assert_contains_key", r#"assertion failed: `(map contains key)`
+    key: `{:?}`,
+    map: `{:?}`: {}"#, &*key, &*map, format_args!($($arg)+))
+                    }
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_contains_key!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that map contains the given key in runtime.
+///
+/// Like [`assert_contains_key!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_contains_key!`]: ./macro.assert_contains_key.html
+#[macro_export]
+macro_rules! debug_assert_contains_key {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_contains_key!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use alloc::collections::BTreeMap;
+    use alloc::string::{String, ToString};
+
+    fn map() -> BTreeMap<String, i32> {
+        let mut map = BTreeMap::new();
+        let _ = map.insert("one".to_string(), 1);
+        let _ = map.insert("two".to_string(), 2);
+        map
+    }
+
+    #[test]
+    fn returns_value() {
+        let map = map();
+        assert_eq!(*assert_contains_key!(map, "one"), 1);
+        assert_eq!(*assert_contains_key!(map, "two".to_string(),), 2);
+        assert_eq!(
+            *assert_contains_key!(&map, "two", "custom {}", "message"),
+            2
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(map contains key)`
+    key: `"three"`,
+    map: `{"one": 1, "two": 2}`"#)]
+    fn default_panic_message() {
+        let map = map();
+        let _ = assert_contains_key!(map, "three");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(map contains key)`
+    key: `"three"`,
+    map: `{"one": 1, "two": 2}`: looking for three"#)]
+    fn custom_panic_message() {
+        let map = map();
+        let _ = assert_contains_key!(map, "three", "looking for {}", "three");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn supports_hash_map() {
+        let mut map = std::collections::HashMap::new();
+        let _ = map.insert(1, "one");
+        assert_eq!(*assert_contains_key!(map, 1), "one");
+    }
+}
@@ -0,0 +1,134 @@
+/// Asserts that left and right expressions have no elements in common.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Both expressions can be arrays, slices, collections or `Clone` iterators,
+/// elements are compared with [`PartialEq`] and their order or duplicates do not matter.
+/// If the right expression is a [`BTreeSet`] (with `alloc` feature) or a [`HashSet`] (with `std` feature),
+/// elements are looked up in it instead of comparing with each one.
+///
+/// Elements of the left expression, which are contained in the right one, are reported on failure.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_disjoint!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let permissions = vec!["read", "write"];
+///
+/// assert_disjoint!(permissions, ["exec"]);
+///
+/// // With custom messages
+/// assert_disjoint!(permissions, ["exec"], "Forbidden permissions in {:?}", permissions);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let permissions = vec!["read", "write"];
+///
+/// assert_disjoint!(permissions, ["write", "exec"]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`BTreeSet`]: https://doc.rust-lang.org/alloc/collections/btree_set/struct.BTreeSet.html
+/// [`HashSet`]: https://doc.rust-lang.org/std/collections/struct.HashSet.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_disjoint!`]: ./macro.debug_assert_disjoint.html
+#[macro_export]
+macro_rules! assert_disjoint {
+    (@fail $fail:tt $left:expr, $right:expr,) => {
+        $crate::assert_disjoint!(@fail $fail $left, $right);
+    };
+    (@fail $fail:tt $left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                use $crate::__private::sets_dispatch::*;
+
+                let common = $crate::__private::Filtered::contained(
+                    (&$crate::__private::Items(left_val)).items(),
+                    (&$crate::__private::Members(right_val)).members((&$crate::__private::Items(right_val)).items()),
+                );
+                if !common.is_empty() {
+                    $crate::__fail!($fail, "assert_disjoint", r#"assertion failed: `(left is disjoint with right)`
+    common elements: {}"#, common);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                use $crate::__private::sets_dispatch::*;
+
+                let common = $crate::__private::Filtered::contained(
+                    (&$crate::__private::Items(left_val)).items(),
+                    (&$crate::__private::Members(right_val)).members((&$crate::__private::Items(right_val)).items()),
+                );
+                if !common.is_empty() {
+                    $crate::__fail!($fail, "assert_disjoint", r#"assertion failed: `(left is disjoint with right)`
+    common elements: {}: {}"#, common, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_disjoint!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that left and right expressions have no elements in common in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_disjoint!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_disjoint!`]: ./macro.assert_disjoint.html
+#[macro_export]
+macro_rules! debug_assert_disjoint {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_disjoint!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_disjoint() {
+        assert_disjoint!([1, 2], [3, 4]);
+        assert_disjoint!([0; 0], [1],);
+        assert_disjoint!([1], [0; 0], "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left is disjoint with right)`
+    common elements: [2, 3]"#)]
+    fn default_panic_message() {
+        assert_disjoint!([1, 2, 3], [3, 4, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left is disjoint with right)`
+    common elements: [1]: checking 1"#)]
+    fn custom_panic_message() {
+        assert_disjoint!([1], [1, 4], "checking {}", 1);
+    }
+}
@@ -0,0 +1,232 @@
+/// Asserts that type-erased value is of the given type.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Value can be a reference to or a [`Box`] (with `alloc` feature) of the `dyn Any`
+/// or, with `std` feature, of the `dyn Error` trait objects,
+/// including their `Send` and `Sync` variants, ex. panic payloads.
+///
+/// On failure, the actual error or the `&str` and `String` panic message is reported.
+/// For values of other types, their [`TypeId`] is reported along with the expected type (on Rust 1.38+).
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_downcast!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use std::any::Any;
+///
+/// # fn main() {
+/// let value: &dyn Any = &42_u8;
+///
+/// assert_downcast!(value, u8);
+///
+/// // With custom messages
+/// assert_downcast!(value, u8, "Expecting a byte");
+/// # }
+/// ```
+///
+/// Reference to the value of the given type will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use std::any::Any;
+///
+/// # fn main() {
+/// let value: &dyn Any = &42_u8;
+///
+/// let byte = assert_downcast!(value, u8);
+/// assert_eq!(*byte, 42);
+/// # }
+/// ```
+///
+#[cfg_attr(
+    feature = "std",
+    doc = r#"It pairs well with [`assert_err!`] for the boxed errors:
+
+```rust
+# #[macro_use] extern crate claim;
+use std::error::Error;
+use std::io;
+
+# fn main() {
+let res: Result<(), Box<dyn Error>> = Err(Box::new(io::Error::from(io::ErrorKind::NotFound)));
+
+let err = assert_err!(res);
+let io_err = assert_downcast!(err, io::Error);
+assert_eq!(io_err.kind(), io::ErrorKind::NotFound);
+# }
+```
+
+[`assert_err!`]: ./macro.assert_err.html
+"#
+)]
+/// Value of another type will cause panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// use std::any::Any;
+///
+/// # fn main() {
+/// let value: &dyn Any = &"text";
+///
+/// assert_downcast!(value, u8);  // Will panic
+/// # }
+/// ```
+///
+/// [`Box`]: https://doc.rust-lang.org/alloc/boxed/struct.Box.html
+/// [`TypeId`]: https://doc.rust-lang.org/core/any/struct.TypeId.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_downcast!`]: ./macro.debug_assert_downcast.html
+#[macro_export]
+macro_rules! assert_downcast {
+    (@fail $fail:tt $value:expr, $ty:ty,) => {
+        $crate::assert_downcast!(@fail $fail $value, $ty)
+    };
+    (@fail $fail:tt $value:expr, $ty:ty) => {
+        match &$value {
+            value => match $crate::__private::downcast::<$ty, _>(value) {
+                Some(target) => target,
+                None => {
+                    $crate::__fail!($fail, "assert_downcast", "assertion failed, expected value of type `{}`, got {}",
+                        stringify!($ty), $crate::__private::actual::<$ty, _>(value));
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $value:expr, $ty:ty, $($arg:tt)+) => {
+        match &$value {
+            value => match $crate::__private::downcast::<$ty, _>(value) {
+                Some(target) => target,
+                None => {
+                    $crate::__fail!($fail, "assert_downcast", "assertion failed, expected value of type `{}`, got {}: {}",
+                        stringify!($ty), $crate::__private::actual::<$ty, _>(value), format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_downcast!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that type-erased value is of the given type in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_downcast!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_downcast!`]: ./macro.assert_downcast.html
+#[macro_export]
+macro_rules! debug_assert_downcast {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_downcast!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::any::Any;
+
+    #[test]
+    fn returns_downcasted_value() {
+        let value: &dyn Any = &42_u8;
+        assert_eq!(*assert_downcast!(value, u8), 42);
+        assert_eq!(*assert_downcast!(value, u8,), 42);
+        assert_eq!(*assert_downcast!(value, u8, "custom {}", "message"), 42);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected value of type `u8`, got `&str` value `\"text\"`"
+    )]
+    fn default_panic_message() {
+        let value: &(dyn Any + Send) = &"text";
+        let _ = assert_downcast!(value, u8);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected value of type `u8`, got value of another type"
+    )]
+    fn custom_panic_message() {
+        let value: &(dyn Any + Send + Sync) = &42_u32;
+        let _ = assert_downcast!(value, u8, "checking {}", "u8");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn downcasts_boxed_errors() {
+        use std::boxed::Box;
+        use std::error::Error;
+        use std::io;
+
+        let err: Box<dyn Error + Send + Sync> = Box::new(io::Error::from(io::ErrorKind::Other));
+        assert_eq!(
+            assert_downcast!(err, io::Error).kind(),
+            io::ErrorKind::Other
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected value of type `std::fmt::Error`, got error `Kind(NotFound)`"
+    )]
+    fn error_panic_message() {
+        use std::boxed::Box;
+        use std::error::Error;
+        use std::io;
+
+        let err: Box<dyn Error> = Box::new(io::Error::from(io::ErrorKind::NotFound));
+        let _ = assert_downcast!(err, std::fmt::Error);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "got `String` value `\"boom 1\"`")]
+    fn panic_payload_message() {
+        let n = 1;
+        let payload = std::panic::catch_unwind(|| panic!("boom {}", n)).unwrap_err();
+        let _ = assert_downcast!(payload, u8);
+    }
+
+    #[cfg(feature = "std")]
+    #[cfg(rustc_1_38)]
+    #[test]
+    fn any_panic_message() {
+        use core::any::TypeId;
+        use std::boxed::Box;
+        use std::string::String;
+
+        let payload: Box<dyn Any + Send> = Box::new(42_u32);
+        let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _ = assert_downcast!(payload, u8, "checking {}", "u8");
+        }))
+        .unwrap_err();
+        assert_eq!(
+            *err.downcast::<String>().unwrap(),
+            std::format!(
+                "assertion failed, expected value of type `u8`, got value of another type with `{:?}`, \
+                 while `u8` has `{:?}`: checking u8",
+                TypeId::of::<u32>(),
+                TypeId::of::<u8>()
+            )
+        );
+    }
+}
@@ -40,25 +40,28 @@
 /// [`debug_assert_err!`]: ./macro.debug_assert_err.html
 #[macro_export]
 macro_rules! assert_err {
-    ($cond:expr,) => {
-        $crate::assert_err!($cond);
+    (@fail $fail:tt $cond:expr,) => {
+        $crate::assert_err!(@fail $fail $cond);
     };
-    ($cond:expr) => {
+    (@fail $fail:tt $cond:expr) => {
         match $cond {
             Ok(t) => {
-                panic!("assertion failed, expected Err(..), got Ok({:?})", t);
+                $crate::__fail!($fail, "assert_err", "assertion failed, expected Err(..), got Ok({:?})", t);
             },
             Err(e) => e,
         }
     };
-    ($cond:expr, $($arg:tt)+) => {
+    (@fail $fail:tt $cond:expr, $($arg:tt)+) => {
         match $cond {
             Ok(t) => {
-                panic!("assertion failed, expected Err(..), got Ok({:?}): {}", t, format_args!($($arg)+));
+                $crate::__fail!($fail, "assert_err", "assertion failed, expected Err(..), got Ok({:?}): {}", t, format_args!($($arg)+));
             },
             Err(e) => e,
         }
     };
+    ($($arg:tt)*) => {
+        $crate::assert_err!(@fail [] $($arg)*)
+    };
 }
 
 /// Asserts that expression returns [`Err(E)`] variant in runtime.
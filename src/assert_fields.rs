@@ -0,0 +1,188 @@
+/// Asserts that fields of the expression value have the expected values.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Each field is given by its path, ex. `address.city`, and either the expected value,
+/// compared with `==` same as [`assert_eq!`] does, or a [matcher], as in [`assert_that!`].
+/// All fields are checked, and every mismatched field is reported
+/// along with the whole value [`Debug`] representation.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_fields!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use claim::matchers::gt;
+///
+/// #[derive(Debug)]
+/// struct Address {
+///     city: String,
+/// }
+///
+/// #[derive(Debug)]
+/// struct User {
+///     name: String,
+///     age: u32,
+///     address: Address,
+/// }
+///
+/// # fn main() {
+/// let user = User {
+///     name: "ann".to_string(),
+///     age: 18,
+///     address: Address { city: "Oslo".to_string() },
+/// };
+///
+/// assert_fields!(user, { name: "ann", age: gt(17), address.city: "Oslo" });
+///
+/// // With custom messages
+/// assert_fields!(user, { age: 18 }, "Checking {}", user.name);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// #[derive(Debug)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// # fn main() {
+/// let point = Point { x: 1, y: 2 };
+///
+/// assert_fields!(point, { x: 0, y: 0 });  // Will panic, reporting both fields
+/// # }
+/// ```
+///
+/// [matcher]: ./matchers/trait.Matcher.html
+/// [`assert_eq!`]: https://doc.rust-lang.org/std/macro.assert_eq.html
+/// [`assert_that!`]: ./macro.assert_that.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_fields!`]: ./macro.debug_assert_fields.html
+#[macro_export]
+macro_rules! assert_fields {
+    (@fail $fail:tt $value:expr, { $($($field:ident).+ : $expected:expr),+ $(,)* },) => {
+        $crate::assert_fields!(@fail $fail $value, { $($($field).+ : $expected),+ });
+    };
+    (@fail $fail:tt $value:expr, { $($($field:ident).+ : $expected:expr),+ $(,)* }) => {
+        match &$value {
+            value => {
+                use $crate::__private::fields_dispatch::*;
+
+                match [$(
+                    $crate::__private::as_field(
+                        &(&$crate::__private::FieldProbe::new(stringify!($($field).+), &value.$($field).+, &$expected)).field()
+                    )
+                ),+] {
+                    fields => {
+                        if !$crate::__private::all_match(&fields) {
+                            $crate::__fail!($fail, "assert_fields", r#"assertion failed: `(value fields match expected)`{}
+    value: `{:?}`"#, $crate::__private::FieldReport(&fields), value)
+                        }
+                    }
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $value:expr, { $($($field:ident).+ : $expected:expr),+ $(,)* }, $($arg:tt)+) => {
+        match &$value {
+            value => {
+                use $crate::__private::fields_dispatch::*;
+
+                match [$(
+                    $crate::__private::as_field(
+                        &(&$crate::__private::FieldProbe::new(stringify!($($field).+), &value.$($field).+, &$expected)).field()
+                    )
+                ),+] {
+                    fields => {
+                        if !$crate::__private::all_match(&fields) {
+                            $crate::__fail!($fail, "assert_fields", r#"assertion failed: `(value fields match expected)`{}
+    value: `{:?}`: {}"#, $crate::__private::FieldReport(&fields), value, format_args!($($arg)+))
+                        }
+                    }
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_fields!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that fields of the expression value have the expected values in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_fields!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_fields!`]: ./macro.assert_fields.html
+#[macro_export]
+macro_rules! debug_assert_fields {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_fields!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use matchers::{gt, lt};
+
+    #[derive(Debug)]
+    struct Inner {
+        value: i32,
+    }
+
+    #[derive(Debug)]
+    struct Outer {
+        name: &'static str,
+        count: u8,
+        inner: Inner,
+    }
+
+    const OUTER: Outer = Outer {
+        name: "foo",
+        count: 3,
+        inner: Inner { value: 42 },
+    };
+
+    #[test]
+    fn accepts_matching_fields() {
+        assert_fields!(OUTER, { name: "foo", count: 3, inner.value: gt(40) });
+        assert_fields!(OUTER, { count: lt(5), inner.value: 42, },);
+        assert_fields!(OUTER, { name: "foo" }, "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(value fields match expected)`
+    name: expected `"bar"`, got `"foo"`,
+    inner.value: expected greater than 50, got `42`,
+    value: `Outer { name: "foo", count: 3, inner: Inner { value: 42 } }`"#)]
+    fn default_panic_message() {
+        assert_fields!(OUTER, { name: "bar", count: 3, inner.value: gt(50) });
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(value fields match expected)`
+    count: expected `4`, got `3`,
+    value: `Outer { name: "foo", count: 3, inner: Inner { value: 42 } }`: checking foo"#)]
+    fn custom_panic_message() {
+        assert_fields!(OUTER, { count: 4 }, "checking {}", OUTER.name);
+    }
+}
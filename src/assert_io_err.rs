@@ -0,0 +1,193 @@
+/// Asserts that expression returns [`Err(E)`] variant
+/// with [`io::Error`] of the given [`io::ErrorKind`].
+///
+/// This macro requires `std` feature and is available for Rust 1.31+.
+///
+/// On mismatch, kind, OS code and message of the actual error are reported.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_io_err!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use std::io;
+///
+/// # fn main() {
+/// let res: io::Result<()> = Err(io::Error::from(io::ErrorKind::NotFound));
+///
+/// assert_io_err!(res, io::ErrorKind::NotFound);
+///
+/// // With custom messages
+/// let res: io::Result<()> = Err(io::Error::from(io::ErrorKind::NotFound));
+/// assert_io_err!(res, io::ErrorKind::NotFound, "Checking {}", "config file");
+/// # }
+/// ```
+///
+/// Value of [`io::Error`] type from `Err(E)` will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use std::io;
+///
+/// # fn main() {
+/// let res: io::Result<()> = Err(io::Error::from(io::ErrorKind::NotFound));
+///
+/// let err = assert_io_err!(res, io::ErrorKind::NotFound);
+/// assert_eq!(err.kind(), io::ErrorKind::NotFound);
+/// # }
+/// ```
+///
+/// Both `Ok(..)` variant and error of another kind will cause panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// use std::io;
+///
+/// # fn main() {
+/// let res: io::Result<()> = Ok(());
+///
+/// assert_io_err!(res, io::ErrorKind::NotFound);  // Will panic
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// use std::io;
+///
+/// # fn main() {
+/// let res: io::Result<()> = Err(io::Error::from(io::ErrorKind::PermissionDenied));
+///
+/// assert_io_err!(res, io::ErrorKind::NotFound);  // Will panic
+/// # }
+/// ```
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`io::Error`]: https://doc.rust-lang.org/std/io/struct.Error.html
+/// [`io::ErrorKind`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_io_err!`]: ./macro.debug_assert_io_err.html
+#[macro_export]
+macro_rules! assert_io_err {
+    (@fail $fail:tt $cond:expr, $expected:expr,) => {
+        $crate::assert_io_err!(@fail $fail $cond, $expected)
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr) => {
+        match ($cond, $expected) {
+            (Ok(t), expected) => {
+                $crate::__fail!($fail, "assert_io_err", "assertion failed, expected Err(..) with kind `{:?}`, got Ok({:?})",
+                    expected, t);
+            }
+            (Err(err), expected) => {
+                let err: std::io::Error = err;
+                if err.kind() != expected {
+                    $crate::__fail!($fail, "assert_io_err", "assertion failed, expected Err(..) with kind `{:?}`{}",
+                        expected, $crate::__private::IoErrorDetails(&err));
+                }
+                err
+            }
+        }
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr, $($arg:tt)+) => {
+        match ($cond, $expected) {
+            (Ok(t), expected) => {
+                $crate::__fail!($fail, "assert_io_err", "assertion failed, expected Err(..) with kind `{:?}`, got Ok({:?}): {}",
+                    expected, t, format_args!($($arg)+));
+            }
+            (Err(err), expected) => {
+                let err: std::io::Error = err;
+                if err.kind() != expected {
+                    $crate::__fail!($fail, "assert_io_err", "assertion failed, expected Err(..) with kind `{:?}`{}: {}",
+                        expected, $crate::__private::IoErrorDetails(&err), format_args!($($arg)+));
+                }
+                err
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_io_err!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that expression returns [`Err(E)`] variant
+/// with [`io::Error`] of the given [`io::ErrorKind`] in runtime.
+///
+/// This macro requires `std` feature and is available for Rust 1.31+.
+///
+/// Like [`assert_io_err!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`io::Error`]: https://doc.rust-lang.org/std/io/struct.Error.html
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_io_err!`]: ./macro.assert_io_err.html
+#[macro_export]
+macro_rules! debug_assert_io_err {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_io_err!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use std::io;
+
+    #[test]
+    fn returns_error() {
+        let res: io::Result<()> = Err(io::Error::other("oops"));
+        assert_eq!(
+            assert_io_err!(res, io::ErrorKind::Other).kind(),
+            io::ErrorKind::Other
+        );
+
+        let res: io::Result<()> = Err(io::Error::from(io::ErrorKind::NotFound));
+        assert_eq!(
+            assert_io_err!(res, io::ErrorKind::NotFound,).kind(),
+            io::ErrorKind::NotFound
+        );
+
+        let res: io::Result<()> = Err(io::Error::from(io::ErrorKind::NotFound));
+        let _ = assert_io_err!(res, io::ErrorKind::NotFound, "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Err(..) with kind `NotFound`, got Ok(())"
+    )]
+    fn ok_panic_message() {
+        let res: io::Result<()> = Ok(());
+        let _ = assert_io_err!(res, io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed, expected Err(..) with kind `NotFound`
+    kind: `Other`,
+    os code: none,
+    message: `oops`"#)]
+    fn default_panic_message() {
+        let res: io::Result<()> = Err(io::Error::other("oops"));
+        let _ = assert_io_err!(res, io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed, expected Err(..) with kind `NotFound`
+    kind: `Other`,
+    os code: none,
+    message: `oops`: checking oops"#)]
+    fn custom_panic_message() {
+        let res: io::Result<()> = Err(io::Error::other("oops"));
+        let _ = assert_io_err!(res, io::ErrorKind::NotFound, "checking {}", "oops");
+    }
+}
@@ -0,0 +1,57 @@
+/// Asserts that expression is exactly of the given type.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Check happens at compile time and no coercions are applied to the expression,
+/// so it is mostly useful to document types of values in tests.
+/// For the same reason, there is no `debug_assert_is!` counterpart.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let value = "42".parse::<u8>();
+///
+/// let value = assert_is!(value: Result<u8, _>);
+/// assert_is!(value.unwrap() + 1: u8);
+/// # }
+/// ```
+///
+/// Expression of another type will fail to compile:
+///
+/// ```rust,compile_fail
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let value = String::from("text");
+///
+/// assert_is!(&value: &str);  // Will not compile
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_is {
+    (@munch [$($value:tt)+] : $ty:ty) => {
+        $crate::__private::is::<$ty, _>($($value)+)
+    };
+    (@munch [$($value:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::assert_is!(@munch [$($value)* $next] $($rest)*)
+    };
+    ($($tokens:tt)+) => {
+        $crate::assert_is!(@munch [] $($tokens)+)
+    };
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn returns_value() {
+        assert_eq!(assert_is!(42_u8: u8), 42);
+        assert_eq!(assert_is!(Some(1 + 1): Option<i32>), Some(2));
+        assert_eq!(assert_is!(core::cmp::max(1_i64, 2): i64), 2);
+        assert_eq!(
+            assert_is!([1, 2].iter().filter(|x: &&i32| -> bool { **x > 1 }).count(): usize),
+            1
+        );
+    }
+}
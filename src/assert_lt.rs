@@ -39,37 +39,40 @@
 /// [`debug_assert_lt!`]: ./macro.debug_assert_lt.html
 #[macro_export]
 macro_rules! assert_lt {
-    ($left:expr, $right:expr) => {
+    (@fail $fail:tt $left:expr, $right:expr) => {
         match (&$left, &$right) {
             (left_val, right_val) => {
                 if !(*left_val < *right_val) {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
-                    panic!(r#"assertion failed: `(left < right)`
+                    $crate::__fail_cmp!($fail, "assert_lt", ($left, $right), left_val, right_val, r#"assertion failed: `(left < right)`
     left: `{:?}`,
     right: `{:?}`"#, &*left_val, &*right_val)
                 }
             }
         }
     };
-    ($left:expr, $right:expr,) => {
-        $crate::assert_lt!($left, $right);
+    (@fail $fail:tt $left:expr, $right:expr,) => {
+        $crate::assert_lt!(@fail $fail $left, $right);
     };
-    ($left:expr, $right:expr, $($arg:tt)+) => {
+    (@fail $fail:tt $left:expr, $right:expr, $($arg:tt)+) => {
         match (&$left, &$right) {
             (left_val, right_val) => {
                 if !(*left_val < *right_val) {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
-                    panic!(r#"assertion failed: `(left < right)`
+                    $crate::__fail_cmp!($fail, "assert_lt", ($left, $right), left_val, right_val, r#"assertion failed: `(left < right)`
     left: `{:?}`,
     right: `{:?}`: {}"#, &*left_val, &*right_val, format_args!($($arg)+))
                 }
             }
         }
     };
+    ($($arg:tt)*) => {
+        $crate::assert_lt!(@fail [] $($arg)*)
+    };
 }
 
 /// Asserts that first expression is less than the second in runtime.
@@ -0,0 +1,162 @@
+/// Asserts that map contains the given key and its value equals to the expected one.
+///
+/// This macro requires `alloc` feature for [`BTreeMap`] support
+/// and `std` feature for [`HashMap`] support.
+///
+/// Key can be of any type the map can be looked up by, ex. `&str` for `String` keys.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_map_entry!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use std::collections::BTreeMap;
+///
+/// # fn main() {
+/// let mut map = BTreeMap::new();
+/// map.insert("answer".to_string(), 42);
+///
+/// assert_map_entry!(map, "answer", 42);
+///
+/// // With custom messages
+/// assert_map_entry!(map, "answer", 42, "Looking for the answer in {:?}", map);
+/// # }
+/// ```
+///
+/// Reference to the value will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use std::collections::BTreeMap;
+///
+/// # fn main() {
+/// let mut map = BTreeMap::new();
+/// map.insert(1, "one");
+///
+/// let value = assert_map_entry!(map, 1, "one");
+/// assert_eq!(*value, "one");
+/// # }
+/// ```
+///
+/// Both missing key and different value will cause panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// use std::collections::BTreeMap;
+///
+/// # fn main() {
+/// let map: BTreeMap<i32, i32> = BTreeMap::new();
+///
+/// assert_map_entry!(map, 1, 1);  // Will panic
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// use std::collections::BTreeMap;
+///
+/// # fn main() {
+/// let mut map = BTreeMap::new();
+/// map.insert(1, 2);
+///
+/// assert_map_entry!(map, 1, 1);  // Will panic
+/// # }
+/// ```
+///
+/// [`BTreeMap`]: https://doc.rust-lang.org/alloc/collections/btree_map/struct.BTreeMap.html
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_map_entry!`]: ./macro.debug_assert_map_entry.html
+#[macro_export]
+macro_rules! assert_map_entry {
+    (@fail $fail:tt $map:expr, $key:expr, $expected:expr,) => {
+        $crate::assert_map_entry!(@fail $fail $map, $key, $expected)
+    };
+    (@fail $fail:tt $map:expr, $key:expr, $expected:expr) => {
+        {
+            let value = $crate::assert_contains_key!(@fail $fail $map, $key);
+            $crate::__assert_eq!($fail, "assert_map_entry", *value, $expected);
+            value
+        }
+    };
+    (@fail $fail:tt $map:expr, $key:expr, $expected:expr, $($arg:tt)+) => {
+        {
+            let value = $crate::assert_contains_key!(@fail $fail $map, $key, $($arg)+);
+            $crate::__assert_eq!($fail, "assert_map_entry", *value, $expected, $($arg)+);
+            value
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_map_entry!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that map contains the given key and its value equals to the expected one in runtime.
+///
+/// Like [`assert_map_entry!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_map_entry!`]: ./macro.assert_map_entry.html
+#[macro_export]
+macro_rules! debug_assert_map_entry {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_map_entry!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use alloc::collections::BTreeMap;
+    use alloc::string::{String, ToString};
+
+    fn map() -> BTreeMap<String, i32> {
+        let mut map = BTreeMap::new();
+        let _ = map.insert("one".to_string(), 1);
+        map
+    }
+
+    #[test]
+    fn returns_value() {
+        let map = map();
+        assert_eq!(*assert_map_entry!(map, "one", 1), 1);
+        assert_eq!(*assert_map_entry!(map, "one", 1,), 1);
+        assert_eq!(*assert_map_entry!(map, "one", 1, "custom {}", "message"), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(map contains key)`
+    key: `"two"`,
+    map: `{"one": 1}`"#)]
+    fn missing_key_panic_message() {
+        let map = map();
+        let _ = assert_map_entry!(map, "two", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "left == right")]
+    fn different_value_panic_message() {
+        let map = map();
+        let _ = assert_map_entry!(map, "one", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "checking one")]
+    fn different_value_custom_panic_message() {
+        let map = map();
+        let _ = assert_map_entry!(map, "one", 2, "checking {}", "one");
+    }
+}
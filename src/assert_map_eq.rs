@@ -0,0 +1,147 @@
+/// Asserts that both maps have the same keys and equal values for them.
+///
+/// This macro requires `alloc` feature for [`BTreeMap`] support
+/// and `std` feature for [`HashMap`] support.
+/// Maps of different types, ex. `HashMap` and `BTreeMap`, can be compared too,
+/// as long as they have the same key type.
+///
+/// On failure, missing keys (present in the right map only), extra keys (present in the left map only)
+/// and keys with different values are reported.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_map_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use std::collections::BTreeMap;
+///
+/// # fn main() {
+/// let mut left = BTreeMap::new();
+/// left.insert("a", 1);
+/// let mut right = BTreeMap::new();
+/// right.insert("a", 1);
+///
+/// assert_map_eq!(left, right);
+///
+/// // With custom messages
+/// assert_map_eq!(left, right, "Comparing {:?}", left);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// use std::collections::BTreeMap;
+///
+/// # fn main() {
+/// let mut left = BTreeMap::new();
+/// left.insert("a", 1);
+/// let mut right = BTreeMap::new();
+/// right.insert("a", 2);
+///
+/// assert_map_eq!(left, right);  // Will panic
+/// # }
+/// ```
+///
+/// [`BTreeMap`]: https://doc.rust-lang.org/alloc/collections/btree_map/struct.BTreeMap.html
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_map_eq!`]: ./macro.debug_assert_map_eq.html
+#[macro_export]
+macro_rules! assert_map_eq {
+    (@fail $fail:tt $left:expr, $right:expr,) => {
+        $crate::assert_map_eq!(@fail $fail $left, $right);
+    };
+    (@fail $fail:tt $left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !$crate::__private::maps_eq(left_val, right_val) {
+                    $crate::__fail!($fail, "assert_map_eq", r#"assertion failed: `(left == right)`{}"#, $crate::__private::map_diff(left_val, right_val));
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !$crate::__private::maps_eq(left_val, right_val) {
+                    $crate::__fail!($fail, "assert_map_eq", r#"assertion failed: `(left == right)`{}: {}"#, $crate::__private::map_diff(left_val, right_val), format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_map_eq!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that both maps have the same keys and equal values for them in runtime.
+///
+/// Like [`assert_map_eq!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_map_eq!`]: ./macro.assert_map_eq.html
+#[macro_export]
+macro_rules! debug_assert_map_eq {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_map_eq!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use alloc::collections::BTreeMap;
+
+    fn map(entries: &[(i32, &'static str)]) -> BTreeMap<i32, &'static str> {
+        entries.iter().cloned().collect()
+    }
+
+    #[test]
+    fn equal_maps() {
+        let left = map(&[(1, "a"), (2, "b")]);
+        let right = map(&[(2, "b"), (1, "a")]);
+        assert_map_eq!(left, right);
+        assert_map_eq!(left, right,);
+        assert_map_eq!(&left, &right, "custom {}", "message");
+        assert_map_eq!(map(&[]), map(&[]));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left == right)`
+    missing keys: [3, 4],
+    extra keys: [1],
+    different values for key `2`: left `"b"`, right `"c"`"#)]
+    fn default_panic_message() {
+        assert_map_eq!(
+            map(&[(1, "a"), (2, "b")]),
+            map(&[(2, "c"), (3, "d"), (4, "e")])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left == right)`
+    extra keys: [1]: comparing maps"#)]
+    fn custom_panic_message() {
+        assert_map_eq!(map(&[(1, "a")]), map(&[]), "comparing {}", "maps");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn compares_hash_map_with_btree_map() {
+        let left: std::collections::HashMap<_, _> = map(&[(1, "a")]).into_iter().collect();
+        assert_map_eq!(left, map(&[(1, "a")]));
+    }
+}
@@ -5,6 +5,12 @@
 /// It works exactly as [`std::matches!`] macro,
 /// except it panics if there is no match.
 ///
+/// Multiple alternatives can be separated with commas, each one with its own guard.
+/// On failure, the first alternative with a matched pattern but a failed guard is reported,
+/// otherwise the report says that none of the patterns matched.
+///
+/// Guards containing top-level `,` or `else` tokens should be wrapped into parentheses.
+///
 /// ## Uses
 ///
 /// Assertions are always checked in both debug and release builds, and cannot be disabled.
@@ -15,6 +21,10 @@
 /// This macro has a second form, where a custom panic message can be provided
 /// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
 ///
+/// Message is separated from the patterns with the `else` keyword.
+/// A comma works too, if the message starts with a string literal,
+/// so alternatives after the first one cannot start with a literal in this case.
+///
 /// ## Examples
 ///
 /// ```rust
@@ -25,6 +35,21 @@
 ///
 /// // With custom messages
 /// assert_matches!(foo, 'A'..='Z' | 'a'..='z', "expecting it to be letter: {}", foo);
+/// assert_matches!(foo, 'A'..='Z' | 'a'..='z' else "expecting it to be letter: {}", foo);
+/// # }
+/// ```
+///
+/// Alternatives with different guards:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let res: Result<u32, &str> = Err("");
+///
+/// assert_matches!(res, Ok(n) if n > 1, Err(s) if s.is_empty());
+///
+/// // With custom messages
+/// assert_matches!(res, Ok(n) if n > 1, Err(s) if s.is_empty() else "unexpected {:?}", res);
 /// # }
 /// ```
 ///
@@ -41,45 +66,169 @@
 /// [`debug_assert_matches!`]: ./macro.debug_assert_matches.html
 #[macro_export]
 macro_rules! assert_matches {
-    ($expression:expr, $( $pattern:pat )|+) => {
-        match $expression {
-            $( $pattern )|+ => {},
+    (@fail $fail:tt $expression:expr, $($rest:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @pattern matches [$expression] [] [] [] $($rest)+)
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_matches!(@fail [] $($arg)*)
+    };
+}
+
+/// Implementation of the `assert_matches!` and `assert_not_matches!` macros.
+///
+/// Alternatives are munched token by token into `{ [pattern] [guard] [text] }` groups,
+/// and then expanded into the match arms with the guards recording the failure details.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_matches {
+    // Every step carries the state of the muncher: `$mode` is either `matches` or `not_matches`,
+    // `$e` is the checked expression, `$alts` are the collected alternatives,
+    // and `$all` is the source of all alternatives for the panic message.
+
+    // Pattern of the current alternative.
+    (@fail $fail:tt @pattern $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] [$($pat:tt)*] if $($rest:tt)*) => {
+        $crate::__assert_matches!(@fail $fail @guard $mode [$e] [$($alts)*] [$($all)*] [$($pat)*] [] $($rest)*)
+    };
+    (@fail $fail:tt @pattern $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] [$($pat:tt)+] , $($rest:tt)*) => {
+        $crate::__assert_matches!(@fail $fail @next $mode [$e] [$($alts)* { [$($pat)+] [true] [$($pat)+] }] [$($all)* $($pat)+] $($rest)*)
+    };
+    (@fail $fail:tt @pattern $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] [$($pat:tt)+] else $($arg:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @done $mode [$e] [$($alts)* { [$($pat)+] [true] [$($pat)+] }] [$($all)* $($pat)+] [$($arg)+])
+    };
+    (@fail $fail:tt @pattern $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] [$($pat:tt)+]) => {
+        $crate::__assert_matches!(@fail $fail @done $mode [$e] [$($alts)* { [$($pat)+] [true] [$($pat)+] }] [$($all)* $($pat)+] [])
+    };
+    (@fail $fail:tt @pattern $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] [$($pat:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__assert_matches!(@fail $fail @pattern $mode [$e] [$($alts)*] [$($all)*] [$($pat)* $next] $($rest)*)
+    };
+
+    // Guard of the current alternative.
+    (@fail $fail:tt @guard $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] [$($pat:tt)+] [$($guard:tt)+] , $($rest:tt)*) => {
+        $crate::__assert_matches!(@fail $fail @next $mode [$e] [$($alts)* { [$($pat)+] [$($guard)+] [$($pat)+ if $($guard)+] }] [$($all)* $($pat)+ if $($guard)+] $($rest)*)
+    };
+    (@fail $fail:tt @guard $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] [$($pat:tt)+] [$($guard:tt)+] else $($arg:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @done $mode [$e] [$($alts)* { [$($pat)+] [$($guard)+] [$($pat)+ if $($guard)+] }] [$($all)* $($pat)+ if $($guard)+] [$($arg)+])
+    };
+    (@fail $fail:tt @guard $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] [$($pat:tt)+] [$($guard:tt)+]) => {
+        $crate::__assert_matches!(@fail $fail @done $mode [$e] [$($alts)* { [$($pat)+] [$($guard)+] [$($pat)+ if $($guard)+] }] [$($all)* $($pat)+ if $($guard)+] [])
+    };
+    (@fail $fail:tt @guard $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] [$($pat:tt)+] [$($guard:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__assert_matches!(@fail $fail @guard $mode [$e] [$($alts)*] [$($all)*] [$($pat)+] [$($guard)* $next] $($rest)*)
+    };
+
+    // Next alternative or the custom message, which starts with a literal or a macro call.
+    (@fail $fail:tt @next $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*]) => {
+        $crate::__assert_matches!(@fail $fail @done $mode [$e] [$($alts)*] [$($all)*] [])
+    };
+    (@fail $fail:tt @next $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $name:tt ! $($arg:tt)*) => {
+        $crate::__assert_matches!(@fail $fail @done $mode [$e] [$($alts)*] [$($all)*] [$name ! $($arg)*])
+    };
+    (@fail $fail:tt @next $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $first:tt $($rest:tt)*) => {
+        $crate::__assert_matches!(@fail $fail @start [$first] $mode [$e] [$($alts)*] [$($all)*] $first $($rest)*)
+    };
+    (@fail $fail:tt @start [$name:ident] $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $($rest:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @pattern $mode [$e] [$($alts)*] [$($all)* ,] [] $($rest)+)
+    };
+    (@fail $fail:tt @start [($($group:tt)*)] $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $($rest:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @pattern $mode [$e] [$($alts)*] [$($all)* ,] [] $($rest)+)
+    };
+    (@fail $fail:tt @start [[$($group:tt)*]] $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $($rest:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @pattern $mode [$e] [$($alts)*] [$($all)* ,] [] $($rest)+)
+    };
+    (@fail $fail:tt @start [_] $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $($rest:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @pattern $mode [$e] [$($alts)*] [$($all)* ,] [] $($rest)+)
+    };
+    (@fail $fail:tt @start [&] $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $($rest:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @pattern $mode [$e] [$($alts)*] [$($all)* ,] [] $($rest)+)
+    };
+    (@fail $fail:tt @start [&&] $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $($rest:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @pattern $mode [$e] [$($alts)*] [$($all)* ,] [] $($rest)+)
+    };
+    (@fail $fail:tt @start [-] $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $($rest:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @pattern $mode [$e] [$($alts)*] [$($all)* ,] [] $($rest)+)
+    };
+    (@fail $fail:tt @start [::] $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $($rest:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @pattern $mode [$e] [$($alts)*] [$($all)* ,] [] $($rest)+)
+    };
+    (@fail $fail:tt @start [<] $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $($rest:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @pattern $mode [$e] [$($alts)*] [$($all)* ,] [] $($rest)+)
+    };
+    (@fail $fail:tt @start [|] $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $($rest:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @pattern $mode [$e] [$($alts)*] [$($all)* ,] [] $($rest)+)
+    };
+    (@fail $fail:tt @start [$literal:tt] $mode:ident [$e:expr] [$($alts:tt)*] [$($all:tt)*] $($arg:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @done $mode [$e] [$($alts)*] [$($all)*] [$($arg)+])
+    };
+
+    (@fail $fail:tt @done matches [$e:expr] [$({ [$($pat:tt)+] [$($guard:tt)+] [$($text:tt)+] })+] [$($all:tt)+] [$($arg:tt)*]) => {{
+        let reason: &mut Option<(&'static str, &'static str)> = &mut None;
+        match $e {
+            $(
+                $($pat)+ if {
+                    let is_matched = { $($guard)+ };
+                    if !is_matched && reason.is_none() {
+                        *reason = Some((stringify!($($pat)+), stringify!($($guard)+)));
+                    }
+                    is_matched
+                } => {}
+            )+
+            other => $crate::__assert_matches!(@fail $fail @panic matches other, *reason, stringify!($($all)+); $($arg)*),
+        }
+    }};
+    (@fail $fail:tt @done not_matches [$e:expr] [$({ [$($pat:tt)+] [$($guard:tt)+] [$($text:tt)+] })+] [$($all:tt)+] [$($arg:tt)*]) => {{
+        let matched: &mut Option<&'static str> = &mut None;
+        match $e {
+            $(
+                $($pat)+ if {
+                    if matched.is_none() && { $($guard)+ } {
+                        *matched = Some(stringify!($($text)+));
+                    }
+                    false
+                } => {}
+            )+
             other => {
-                panic!(r#"assertion failed, expression does not match any of the given variants.
-    expression: {:?}
-    variants: {}"#, other, stringify!($($pattern) |+));
+                if let Some(matched) = *matched {
+                    $crate::__assert_matches!(@fail $fail @panic not_matches other, matched, stringify!($($all)+); $($arg)*);
+                }
             }
         }
-    };
-    ($expression:expr, $( $pattern:pat )|+ if $guard: expr) => {
-        match $expression {
-            $( $pattern )|+ if $guard => {},
-            other => {
-                panic!(r#"assertion failed, expression does not match any of the given variants.
+    }};
+
+    (@fail $fail:tt @panic matches $other:ident, $reason:expr, $variants:expr;) => {
+        match $reason {
+            Some((pattern, guard)) => $crate::__fail!($fail, "assert_matches", r#"assertion failed, expression does not match any of the given variants.
     expression: {:?}
-    variants: {}"#, other, stringify!($($pattern) |+ if $guard));
-            }
+    variants: {}
+    reason: pattern `{}` matched, but guard `{}` failed"#, $other, $variants, pattern, guard),
+            None => $crate::__fail!($fail, "assert_matches", r#"assertion failed, expression does not match any of the given variants.
+    expression: {:?}
+    variants: {}
+    reason: no pattern matched"#, $other, $variants),
         }
     };
-    ($expression:expr, $( $pattern:pat )|+, $($arg:tt)+) => {
-        match $expression {
-            $( $pattern )|+ => {},
-            other => {
-                panic!(r#"assertion failed, expression does not match any of the given variants.
+    (@fail $fail:tt @panic matches $other:ident, $reason:expr, $variants:expr; $($arg:tt)+) => {
+        match $reason {
+            Some((pattern, guard)) => $crate::__fail!($fail, "assert_matches", r#"assertion failed, expression does not match any of the given variants.
     expression: {:?}
-    variants: {}: {}"#, other, stringify!($($pattern) |+), format_args!($($arg)+));
-            }
+    variants: {}
+    reason: pattern `{}` matched, but guard `{}` failed: {}"#, $other, $variants, pattern, guard, format_args!($($arg)+)),
+            None => $crate::__fail!($fail, "assert_matches", r#"assertion failed, expression does not match any of the given variants.
+    expression: {:?}
+    variants: {}
+    reason: no pattern matched: {}"#, $other, $variants, format_args!($($arg)+)),
         }
     };
-    ($expression:expr, $( $pattern:pat )|+ if $guard: expr, $($arg:tt)+) => {
-        match $expression {
-            $( $pattern )|+ if $guard => {},
-            other => {
-                panic!(r#"assertion failed, expression does not match any of the given variants.
+    (@fail $fail:tt @panic not_matches $other:ident, $matched:expr, $variants:expr;) => {
+        $crate::__fail!($fail, "assert_not_matches", r#"assertion failed, expression matches one of the given variants.
     expression: {:?}
-    variants: {}: {}"#, other, stringify!($($pattern) |+ if $guard), format_args!($($arg)+));
-            }
-        }
+    variants: {}
+    matched: `{}`"#, $other, $variants, $matched)
+    };
+    (@fail $fail:tt @panic not_matches $other:ident, $matched:expr, $variants:expr; $($arg:tt)+) => {
+        $crate::__fail!($fail, "assert_not_matches", r#"assertion failed, expression matches one of the given variants.
+    expression: {:?}
+    variants: {}
+    matched: `{}`: {}"#, $other, $variants, $matched, format_args!($($arg)+))
     };
 }
 
@@ -100,3 +249,88 @@ macro_rules! assert_matches {
 macro_rules! debug_assert_matches {
     ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_matches!($($arg)*); })
 }
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug, Clone, Copy)]
+    enum Shape {
+        Circle(u32),
+        Square(u32, u32),
+        Triangle,
+    }
+
+    #[test]
+    fn matches() {
+        let shape = Shape::Square(2, 3);
+        assert_matches!(shape, Shape::Square(..));
+        assert_matches!(shape, Shape::Circle(_) | Shape::Square(..),);
+        assert_matches!(shape, Shape::Square(w, h) if w < h, "checking {:?}", shape);
+        assert_matches!(shape, Shape::Circle(r) if r > 1, Shape::Square(w, _) if w > 1);
+        assert_matches!(shape, Shape::Circle(_), Shape::Square(..),);
+        assert_matches!(shape, Shape::Circle(r) if r > 1, Shape::Square(..) else "checking {:?}", shape);
+        assert_matches!((shape, 1), (Shape::Triangle, _), (Shape::Square(..), 1));
+        assert_matches!([1, 2], [0, ..], [1, _]);
+        assert_matches!(-1, 1 if false, -1 | 0);
+        assert_matches!(&Some(1), &None, &Some(1));
+        assert_matches!(Shape::Triangle, _ if false, Shape::Triangle);
+    }
+
+    #[test]
+    fn string_literals() {
+        let name = "bar";
+        assert_matches!(name, "foo" | "bar", "checking {}", name);
+        assert_matches!(name, "bar", s if s.len() > 5 else "checking {}", name);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expression does not match any of the given variants.
+    expression: Circle(1)
+    variants: Shape::Square(..)
+    reason: no pattern matched"#
+    )]
+    fn default_panic_message() {
+        assert_matches!(Shape::Circle(1), Shape::Square(..));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expression does not match any of the given variants.
+    expression: Square(2, 3)
+    variants: Shape::Circle(_), Shape::Square(w, h) if w > h, Shape::Square(w, _) if w > 2
+    reason: pattern `Shape::Square(w, h)` matched, but guard `w > h` failed"#
+    )]
+    fn guard_panic_message() {
+        assert_matches!(
+            Shape::Square(2, 3),
+            Shape::Circle(_),
+            Shape::Square(w, h) if w > h,
+            Shape::Square(w, _) if w > 2
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expression does not match any of the given variants.
+    expression: Square(2, 3)
+    variants: Shape::Circle(r) if r > 1
+    reason: no pattern matched: checking Square(2, 3)"#
+    )]
+    fn custom_panic_message() {
+        let shape = Shape::Square(2, 3);
+        assert_matches!(shape, Shape::Circle(r) if r > 1, "checking {:?}", shape);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expression does not match any of the given variants.
+    expression: Circle(1)
+    variants: Shape::Circle(r) if r > 1, Shape::Square(..)
+    reason: pattern `Shape::Circle(r)` matched, but guard `r > 1` failed: checking Circle(1)"#
+    )]
+    fn else_panic_message() {
+        let shape = Shape::Circle(1);
+        assert_matches!(shape, Shape::Circle(r) if r > 1, Shape::Square(..) else "checking {:?}", shape);
+    }
+}
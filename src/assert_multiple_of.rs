@@ -0,0 +1,121 @@
+/// Asserts that the integer is a multiple of the divisor.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Value and the divisor can be of any primitive integer type, same for both of them.
+/// Only zero is a multiple of zero.
+///
+/// On failure, the remainder of the division is reported.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_multiple_of!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let size: usize = 4096;
+///
+/// assert_multiple_of!(size, 512);
+///
+/// // With custom messages
+/// assert_multiple_of!(size, 512, "Expecting that {} is a multiple of the sector size", size);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let size: usize = 1000;
+///
+/// assert_multiple_of!(size, 512);  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_multiple_of!`]: ./macro.debug_assert_multiple_of.html
+#[macro_export]
+macro_rules! assert_multiple_of {
+    (@fail $fail:tt $value:expr, $divisor:expr,) => {
+        $crate::assert_multiple_of!(@fail $fail $value, $divisor);
+    };
+    (@fail $fail:tt $value:expr, $divisor:expr) => {
+        match (&$value, &$divisor) {
+            (value, divisor) => {
+                let remainder = $crate::__private::Integer::remainder(*value, *divisor);
+                if !$crate::__private::Integer::is_zero(remainder) {
+                    $crate::__fail!($fail, "assert_multiple_of", "assertion failed, expected multiple of `{:?}`, got `{:?}` with remainder `{:?}`", divisor, value, remainder);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $value:expr, $divisor:expr, $($arg:tt)+) => {
+        match (&$value, &$divisor) {
+            (value, divisor) => {
+                let remainder = $crate::__private::Integer::remainder(*value, *divisor);
+                if !$crate::__private::Integer::is_zero(remainder) {
+                    $crate::__fail!($fail, "assert_multiple_of", "assertion failed, expected multiple of `{:?}`, got `{:?}` with remainder `{:?}`: {}", divisor, value, remainder, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_multiple_of!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that the integer is a multiple of the divisor in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_multiple_of!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_multiple_of!`]: ./macro.assert_multiple_of.html
+#[macro_export]
+macro_rules! debug_assert_multiple_of {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_multiple_of!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn multiple_of() {
+        assert_multiple_of!(12u8, 4);
+        assert_multiple_of!(-12i64, 3,);
+        assert_multiple_of!(0u32, 0, "custom {}", "message");
+        assert_multiple_of!(-1i32 << 31, -1);
+        debug_assert_multiple_of!(10usize, 5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected multiple of `4`, got `10` with remainder `2`"
+    )]
+    fn default_panic_message() {
+        assert_multiple_of!(10u32, 4);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected multiple of `0`, got `3` with remainder `3`: custom message"
+    )]
+    fn custom_panic_message() {
+        assert_multiple_of!(3i16, 0, "custom {}", "message");
+    }
+}
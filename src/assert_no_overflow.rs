@@ -0,0 +1,115 @@
+/// Asserts that the checked arithmetic operation does not overflow, i.e. returns `Some(T)`.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// On success, this macro will return the result of the operation.
+///
+/// Operation is usually a call of the `checked_*` method, ex. `a.checked_mul(b)`,
+/// in which case its receiver and argument are reported on failure as the `left`
+/// and `right` operands (or as the `value` for methods without arguments, ex. `checked_neg()`).
+/// Closure arguments, ex. of the `and_then` method, and operands,
+/// which do not implement `Debug`, are not reported.
+/// Any other expression of the `Option` type is accepted too.
+///
+/// Operands are evaluated only once and are used again for the report after the call,
+/// so they should be `Copy`, as primitive integers are.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_no_overflow!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let a: u8 = 100;
+///
+/// let product = assert_no_overflow!(a.checked_mul(2));
+/// assert_eq!(product, 200);
+///
+/// // With custom messages
+/// assert_no_overflow!(a.checked_add(a), "Expecting that {} + {} fits", a, a);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let a: u8 = 200;
+///
+/// assert_no_overflow!(a.checked_mul(2));  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_no_overflow!`]: ./macro.debug_assert_no_overflow.html
+#[macro_export]
+macro_rules! assert_no_overflow {
+    (@fail $fail:tt $($arg:tt)+) => {
+        $crate::__assert_overflow!(@fail $fail @split no_overflow [] $($arg)+)
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_no_overflow!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that the checked arithmetic operation does not overflow in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_no_overflow!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_no_overflow!`]: ./macro.assert_no_overflow.html
+#[macro_export]
+macro_rules! debug_assert_no_overflow {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { let _ = $crate::assert_no_overflow!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn no_overflow() {
+        let a: u8 = 100;
+        assert_eq!(assert_no_overflow!(a.checked_mul(2)), 200);
+        assert_eq!(assert_no_overflow!((-5i32).checked_abs(),), 5);
+        assert_eq!(assert_no_overflow!(Some(1), "custom {}", "message"), 1);
+        debug_assert_no_overflow!(a.checked_sub(1));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected `a.checked_mul(2)` not to overflow, got None
+    left: `200`,
+    right: `2`"
+    )]
+    fn default_panic_message() {
+        let a: u8 = 200;
+        let _ = assert_no_overflow!(a.checked_mul(2));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected `i32::max_value().checked_add(b)` not to overflow, got None
+    left: `2147483647`,
+    right: `1`: custom message"
+    )]
+    fn custom_panic_message() {
+        let b = 1;
+        let _ = assert_no_overflow!(i32::max_value().checked_add(b), "custom {}", "message");
+    }
+}
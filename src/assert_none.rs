@@ -41,25 +41,28 @@
 /// [`debug_assert_none!`]: ./macro.debug_assert_none.html
 #[macro_export]
 macro_rules! assert_none {
-    ($cond:expr,) => {
-        $crate::assert_none!($cond);
+    (@fail $fail:tt $cond:expr,) => {
+        $crate::assert_none!(@fail $fail $cond);
     };
-    ($cond:expr) => {
+    (@fail $fail:tt $cond:expr) => {
         match $cond {
             n @ None => n,
             t @ Some(..) => {
-                panic!("assertion failed, expected None, got {:?}", t);
+                $crate::__fail!($fail, "assert_none", "assertion failed, expected None, got {:?}", t);
             }
         }
     };
-    ($cond:expr, $($arg:tt)+) => {
+    (@fail $fail:tt $cond:expr, $($arg:tt)+) => {
         match $cond {
             n @ None => n,
             t @ Some(..) => {
-                panic!("assertion failed, expected None, got {:?}: {}", t, format_args!($($arg)+));
+                $crate::__fail!($fail, "assert_none", "assertion failed, expected None, got {:?}: {}", t, format_args!($($arg)+));
             }
         }
     };
+    ($($arg:tt)*) => {
+        $crate::assert_none!(@fail [] $($arg)*)
+    };
 }
 
 /// Asserts that expression returns [`None`] variant in runtime.
@@ -0,0 +1,167 @@
+/// Asserts that no elements match any of the given variants.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Elements can be provided as an array, slice, collection or a `Clone` iterator.
+/// Patterns and guards are the same to the [`assert_matches!`] ones.
+///
+/// On failure, indexes and values of the matched elements are reported,
+/// up to ten of them.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_none_match!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let responses: Vec<Result<u16, &str>> = vec![Ok(200), Ok(404)];
+///
+/// assert_none_match!(responses, Err(_));
+///
+/// // With custom messages
+/// assert_none_match!(responses, Ok(code) if *code >= 500, "Server errors in {:?}", responses);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let responses: Vec<Result<u16, &str>> = vec![Ok(200), Ok(404)];
+///
+/// assert_none_match!(responses, Ok(400..=499));  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_none_match!`]: ./macro.debug_assert_none_match.html
+#[macro_export]
+macro_rules! assert_none_match {
+    (@fail $fail:tt $items:expr, $( $pattern:pat )|+) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let result = $crate::__private::none((&$crate::__private::Items(items_val)).items(), |item| match item {
+                    $( $pattern )|+ => true,
+                    _ => false,
+                });
+                if !result.is_ok() {
+                    $crate::__fail!($fail, "assert_none_match", r#"assertion failed: `(no elements match pattern)`
+    pattern: {},{}"#, stringify!($($pattern) |+), result);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $items:expr, $( $pattern:pat )|+ if $guard:expr) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let result = $crate::__private::none((&$crate::__private::Items(items_val)).items(), |item| match item {
+                    $( $pattern )|+ if $guard => true,
+                    _ => false,
+                });
+                if !result.is_ok() {
+                    $crate::__fail!($fail, "assert_none_match", r#"assertion failed: `(no elements match pattern)`
+    pattern: {},{}"#, stringify!($($pattern) |+ if $guard), result);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $items:expr, $( $pattern:pat )|+, $($arg:tt)+) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let result = $crate::__private::none((&$crate::__private::Items(items_val)).items(), |item| match item {
+                    $( $pattern )|+ => true,
+                    _ => false,
+                });
+                if !result.is_ok() {
+                    $crate::__fail!($fail, "assert_none_match", r#"assertion failed: `(no elements match pattern)`
+    pattern: {},{}: {}"#, stringify!($($pattern) |+), result, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $items:expr, $( $pattern:pat )|+ if $guard:expr, $($arg:tt)+) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+
+                let result = $crate::__private::none((&$crate::__private::Items(items_val)).items(), |item| match item {
+                    $( $pattern )|+ if $guard => true,
+                    _ => false,
+                });
+                if !result.is_ok() {
+                    $crate::__fail!($fail, "assert_none_match", r#"assertion failed: `(no elements match pattern)`
+    pattern: {},{}: {}"#, stringify!($($pattern) |+ if $guard), result, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_none_match!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that no elements match any of the given variants in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_none_match!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_none_match!`]: ./macro.assert_none_match.html
+#[macro_export]
+macro_rules! debug_assert_none_match {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_none_match!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_no_matches() {
+        assert_none_match!([Some(1), Some(2)], None);
+        assert_none_match!([Some(1), None], Some(2) | Some(3));
+        assert_none_match!([Some(1), Some(2)], Some(x) if *x > 2);
+        assert_none_match!([1, 2], 3..=4, "custom {}", "message");
+        assert_none_match!([1, 2], x if *x > 3, "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(no elements match pattern)`
+    pattern: None,
+    matched elements: 2 of 3,
+    [0]: `None`,
+    [2]: `None`"#)]
+    fn default_panic_message() {
+        assert_none_match!([None, Some(1), None], None);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(no elements match pattern)`
+    pattern: Some(x) if *x > 1,
+    matched elements: 1 of 2,
+    [1]: `Some(2)`: checking 2"#)]
+    fn custom_panic_message() {
+        assert_none_match!([Some(1), Some(2)], Some(x) if *x > 1, "checking {}", 2);
+    }
+}
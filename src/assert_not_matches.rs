@@ -0,0 +1,131 @@
+/// Asserts that expression does not match any of the given variants.
+///
+/// This macro is available for Rust 1.26+.
+///
+/// It is the negation of the [`assert_matches!`] macro, with the same patterns and guards.
+/// On failure, the expression and the first alternative, which matched it, are reported.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_not_matches!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// Message is separated from the patterns the same way as in the [`assert_matches!`] macro.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let foo = '1';
+/// assert_not_matches!(foo, 'A'..='Z' | 'a'..='z');
+///
+/// // With custom messages
+/// assert_not_matches!(foo, 'A'..='Z' | 'a'..='z', "expecting it not to be letter: {}", foo);
+/// assert_not_matches!(foo, 'A'..='Z', c if c.is_whitespace() else "unexpected {:?}", foo);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let bar: Option<i32> = Some(3);
+/// assert_not_matches!(bar, Some(x) if x > 2);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_not_matches!`]: ./macro.debug_assert_not_matches.html
+#[macro_export]
+macro_rules! assert_not_matches {
+    (@fail $fail:tt $expression:expr, $($rest:tt)+) => {
+        $crate::__assert_matches!(@fail $fail @pattern not_matches [$expression] [] [] [] $($rest)+)
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_not_matches!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that expression does not match any of the given variants in runtime.
+///
+/// Like [`assert_not_matches!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_not_matches!`]: ./macro.assert_not_matches.html
+#[macro_export]
+macro_rules! debug_assert_not_matches {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_not_matches!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug, Clone, Copy)]
+    enum State {
+        Idle,
+        Running(u32),
+        Error { code: u32 },
+    }
+
+    #[test]
+    fn does_not_match() {
+        let state = State::Running(1);
+        assert_not_matches!(state, State::Error { .. });
+        assert_not_matches!(state, State::Idle | State::Error { .. },);
+        assert_not_matches!(state, State::Running(n) if n > 1, "checking {:?}", state);
+        assert_not_matches!(state, State::Idle, State::Running(n) if n > 1 else "checking {:?}", state);
+        assert_not_matches!(State::Idle, State::Error { code } if code > 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expression matches one of the given variants.
+    expression: Error { code: 2 }
+    variants: State::Idle, State::Error { code } if code > 1, State::Error { .. }
+    matched: `State::Error { code } if code > 1`"#
+    )]
+    fn default_panic_message() {
+        assert_not_matches!(
+            State::Error { code: 2 },
+            State::Idle,
+            State::Error { code } if code > 1,
+            State::Error { .. }
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expression matches one of the given variants.
+    expression: Running(1)
+    variants: State::Running(_)
+    matched: `State::Running(_)`: checking Running(1)"#
+    )]
+    fn custom_panic_message() {
+        let state = State::Running(1);
+        assert_not_matches!(state, State::Running(_), "checking {:?}", state);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expression matches one of the given variants.
+    expression: Running(1)
+    variants: State::Idle, State::Running(_)
+    matched: `State::Running(_)`: checking Running(1)"#
+    )]
+    fn else_panic_message() {
+        let state = State::Running(1);
+        assert_not_matches!(state, State::Idle, State::Running(_) else "checking {:?}", state);
+    }
+}
@@ -52,25 +52,28 @@
 /// [`debug_assert_ok!`]: ./macro.debug_assert_ok.html
 #[macro_export]
 macro_rules! assert_ok {
-    ($cond:expr,) => {
-        $crate::assert_ok!($cond);
+    (@fail $fail:tt $cond:expr,) => {
+        $crate::assert_ok!(@fail $fail $cond);
     };
-    ($cond:expr) => {
+    (@fail $fail:tt $cond:expr) => {
         match $cond {
             Ok(t) => t,
             Err(e) => {
-                panic!("assertion failed, expected Ok(..), got Err({:?})", e);
+                $crate::__fail!($fail, "assert_ok", "assertion failed, expected Ok(..), got Err({:?})", e);
             }
         }
     };
-    ($cond:expr, $($arg:tt)+) => {
+    (@fail $fail:tt $cond:expr, $($arg:tt)+) => {
         match $cond {
             Ok(t) => t,
             Err(e) => {
-                panic!("assertion failed, expected Ok(..), got Err({:?}): {}", e, format_args!($($arg)+));
+                $crate::__fail!($fail, "assert_ok", "assertion failed, expected Ok(..), got Err({:?}): {}", e, format_args!($($arg)+));
             }
         }
     };
+    ($($arg:tt)*) => {
+        $crate::assert_ok!(@fail [] $($arg)*)
+    };
 }
 
 /// Asserts that expression returns [`Ok(T)`] variant in runtime.
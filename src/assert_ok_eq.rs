@@ -53,31 +53,34 @@
 /// [`debug_assert_ok_eq!`]: ./macro.debug_assert_ok_eq.html
 #[macro_export]
 macro_rules! assert_ok_eq {
-    ($cond:expr, $expected:expr,) => {
-        $crate::assert_ok_eq!($cond, $expected);
+    (@fail $fail:tt $cond:expr, $expected:expr,) => {
+        $crate::assert_ok_eq!(@fail $fail $cond, $expected);
     };
-    ($cond:expr, $expected:expr) => {
+    (@fail $fail:tt $cond:expr, $expected:expr) => {
         match $cond {
             Ok(t) => {
-                assert_eq!(t, $expected);
+                $crate::__assert_eq!($fail, "assert_ok_eq", t, $expected);
                 t
             },
             e @ Err(..) => {
-                panic!("assertion failed, expected Ok(..), got {:?}", e);
+                $crate::__fail!($fail, "assert_ok_eq", "assertion failed, expected Ok(..), got {:?}", e);
             }
         }
     };
-    ($cond:expr, $expected:expr, $($arg:tt)+) => {
+    (@fail $fail:tt $cond:expr, $expected:expr, $($arg:tt)+) => {
         match $cond {
             Ok(t) => {
-                assert_eq!(t, $expected);
+                $crate::__assert_eq!($fail, "assert_ok_eq", t, $expected);
                 t
             },
             e @ Err(..) => {
-                panic!("assertion failed, expected Ok(..), got {:?}: {}", e, format_args!($($arg)+));
+                $crate::__fail!($fail, "assert_ok_eq", "assertion failed, expected Ok(..), got {:?}: {}", e, format_args!($($arg)+));
             }
         }
     };
+    ($($arg:tt)*) => {
+        $crate::assert_ok_eq!(@fail [] $($arg)*)
+    };
 }
 
 /// Asserts that expression returns [`Ok(T)`] variant in runtime.
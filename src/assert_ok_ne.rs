@@ -0,0 +1,154 @@
+/// Asserts that expression returns [`Ok(T)`] variant
+/// and its value of `T` type does not equal to the right expression.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ok_ne!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let res: Result<i32, ()> = Ok(1);
+///
+/// assert_ok_ne!(res, 2);
+///
+/// // With custom messages
+/// assert_ok_ne!(res, 2, "Everything is good with {:?}", res);
+/// # }
+/// ```
+///
+/// Value of `T` type from `Ok(T)` will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let res: Result<i32, ()> = Ok(1);
+///
+/// let value = assert_ok_ne!(res, 2);
+/// assert_eq!(value, 1);
+/// # }
+/// ```
+///
+/// Both `Err(..)` variant and equal values will cause panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let res: Result<i32, ()> = Err(());
+///
+/// assert_ok_ne!(res, 2);  // Will panic
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let res: Result<i32, ()> = Ok(2);
+///
+/// assert_ok_ne!(res, 2);  // Will panic
+/// # }
+/// ```
+///
+/// [`Ok(T)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ok_ne!`]: ./macro.debug_assert_ok_ne.html
+#[macro_export]
+macro_rules! assert_ok_ne {
+    (@fail $fail:tt $cond:expr, $expected:expr,) => {
+        $crate::assert_ok_ne!(@fail $fail $cond, $expected)
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr) => {
+        match $cond {
+            Ok(t) => {
+                $crate::__assert_ne!($fail, "assert_ok_ne", t, $expected);
+                t
+            },
+            e @ Err(..) => {
+                $crate::__fail!($fail, "assert_ok_ne", "assertion failed, expected Ok(..), got {:?}", e);
+            }
+        }
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr, $($arg:tt)+) => {
+        match $cond {
+            Ok(t) => {
+                $crate::__assert_ne!($fail, "assert_ok_ne", t, $expected, $($arg)+);
+                t
+            },
+            e @ Err(..) => {
+                $crate::__fail!($fail, "assert_ok_ne", "assertion failed, expected Ok(..), got {:?}: {}", e, format_args!($($arg)+));
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_ok_ne!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that expression returns [`Ok(T)`] variant
+/// and its value of `T` type does not equal to the right expression in runtime.
+///
+/// Like [`assert_ok_ne!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Ok(T)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_ok_ne!`]: ./macro.assert_ok_ne.html
+#[macro_export]
+macro_rules! debug_assert_ok_ne {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_ok_ne!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn returns_ok_value() {
+        let res: Result<i32, ()> = Ok(1);
+        assert_eq!(assert_ok_ne!(res, 2), 1);
+        assert_eq!(assert_ok_ne!(res, 2,), 1);
+        assert_eq!(assert_ok_ne!(res, 2, "custom {}", "message"), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ok(..), got Err(())")]
+    fn default_panic_message() {
+        let res: Result<i32, ()> = Err(());
+        let _ = assert_ok_ne!(res, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(..), got Err(()): we are checking Err(())"
+    )]
+    fn custom_panic_message() {
+        let res: Result<i32, ()> = Err(());
+        let _ = assert_ok_ne!(res, 2, "we are checking {:?}", res);
+    }
+
+    #[test]
+    #[should_panic(expected = "left != right")]
+    fn equal_panic_message() {
+        let res: Result<i32, ()> = Ok(2);
+        let _ = assert_ok_ne!(res, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "we are checking Ok(2)")]
+    fn equal_custom_panic_message() {
+        let res: Result<i32, ()> = Ok(2);
+        let _ = assert_ok_ne!(res, 2, "we are checking {:?}", res);
+    }
+}
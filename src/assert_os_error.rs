@@ -0,0 +1,181 @@
+/// Asserts that expression returns [`Err(E)`] variant
+/// with [`io::Error`] of the given OS error code.
+///
+/// This macro requires `std` feature and is available for Rust 1.31+.
+///
+/// On mismatch, kind, OS code and message of the actual error are reported.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_os_error!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use std::io;
+///
+/// # fn main() {
+/// let res: io::Result<()> = Err(io::Error::from_raw_os_error(2));
+///
+/// assert_os_error!(res, 2);
+///
+/// // With custom messages
+/// let res: io::Result<()> = Err(io::Error::from_raw_os_error(2));
+/// assert_os_error!(res, 2, "Checking {}", "config file");
+/// # }
+/// ```
+///
+/// Value of [`io::Error`] type from `Err(E)` will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use std::io;
+///
+/// # fn main() {
+/// let res: io::Result<()> = Err(io::Error::from_raw_os_error(2));
+///
+/// let err = assert_os_error!(res, 2);
+/// assert_eq!(err.raw_os_error(), Some(2));
+/// # }
+/// ```
+///
+/// Both `Ok(..)` variant and error with another code will cause panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// use std::io;
+///
+/// # fn main() {
+/// let res: io::Result<()> = Ok(());
+///
+/// assert_os_error!(res, 2);  // Will panic
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// use std::io;
+///
+/// # fn main() {
+/// let res: io::Result<()> = Err(io::Error::from_raw_os_error(13));
+///
+/// assert_os_error!(res, 2);  // Will panic
+/// # }
+/// ```
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`io::Error`]: https://doc.rust-lang.org/std/io/struct.Error.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_os_error!`]: ./macro.debug_assert_os_error.html
+#[macro_export]
+macro_rules! assert_os_error {
+    (@fail $fail:tt $cond:expr, $expected:expr,) => {
+        $crate::assert_os_error!(@fail $fail $cond, $expected)
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr) => {
+        match ($cond, $expected) {
+            (Ok(t), expected) => {
+                $crate::__fail!($fail, "assert_os_error", "assertion failed, expected Err(..) with OS code `{:?}`, got Ok({:?})",
+                    expected, t);
+            }
+            (Err(err), expected) => {
+                let err: std::io::Error = err;
+                if err.raw_os_error() != Some(expected) {
+                    $crate::__fail!($fail, "assert_os_error", "assertion failed, expected Err(..) with OS code `{:?}`{}",
+                        expected, $crate::__private::IoErrorDetails(&err));
+                }
+                err
+            }
+        }
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr, $($arg:tt)+) => {
+        match ($cond, $expected) {
+            (Ok(t), expected) => {
+                $crate::__fail!($fail, "assert_os_error", "assertion failed, expected Err(..) with OS code `{:?}`, got Ok({:?}): {}",
+                    expected, t, format_args!($($arg)+));
+            }
+            (Err(err), expected) => {
+                let err: std::io::Error = err;
+                if err.raw_os_error() != Some(expected) {
+                    $crate::__fail!($fail, "assert_os_error", "assertion failed, expected Err(..) with OS code `{:?}`{}: {}",
+                        expected, $crate::__private::IoErrorDetails(&err), format_args!($($arg)+));
+                }
+                err
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_os_error!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that expression returns [`Err(E)`] variant
+/// with [`io::Error`] of the given OS error code in runtime.
+///
+/// This macro requires `std` feature and is available for Rust 1.31+.
+///
+/// Like [`assert_os_error!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`io::Error`]: https://doc.rust-lang.org/std/io/struct.Error.html
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_os_error!`]: ./macro.assert_os_error.html
+#[macro_export]
+macro_rules! debug_assert_os_error {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_os_error!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use std::io;
+
+    #[test]
+    fn returns_error() {
+        let res: io::Result<()> = Err(io::Error::from_raw_os_error(2));
+        assert_eq!(assert_os_error!(res, 2).raw_os_error(), Some(2));
+
+        let res: io::Result<()> = Err(io::Error::from_raw_os_error(2));
+        let _ = assert_os_error!(res, 2,);
+
+        let res: io::Result<()> = Err(io::Error::from_raw_os_error(2));
+        let _ = assert_os_error!(res, 2, "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Err(..) with OS code `2`, got Ok(())")]
+    fn ok_panic_message() {
+        let res: io::Result<()> = Ok(());
+        let _ = assert_os_error!(res, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed, expected Err(..) with OS code `2`
+    kind: `Other`,
+    os code: none,
+    message: `oops`"#)]
+    fn default_panic_message() {
+        let res: io::Result<()> = Err(io::Error::other("oops"));
+        let _ = assert_os_error!(res, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "os code: `13`")]
+    fn os_code_panic_message() {
+        let res: io::Result<()> = Err(io::Error::from_raw_os_error(13));
+        let _ = assert_os_error!(res, 2, "checking {}", 13);
+    }
+}
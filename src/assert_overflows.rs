@@ -0,0 +1,255 @@
+/// Asserts that the checked arithmetic operation overflows, i.e. returns `None`.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Operation is usually a call of the `checked_*` method, ex. `a.checked_mul(b)`,
+/// in which case its receiver and argument are reported on failure as the `left`
+/// and `right` operands (or as the `value` for methods without arguments, ex. `checked_neg()`),
+/// along with the result of the operation.
+/// Closure arguments, ex. of the `and_then` method, and operands,
+/// which do not implement `Debug`, are not reported.
+/// Any other expression of the `Option` type is accepted too, and only its value is reported.
+///
+/// Operands are evaluated only once and are used again for the report after the call,
+/// so they should be `Copy`, as primitive integers are.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_overflows!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let a: u8 = 200;
+///
+/// assert_overflows!(a.checked_mul(2));
+/// assert_overflows!(0u8.checked_sub(1));
+///
+/// // With custom messages
+/// assert_overflows!(a.checked_add(a), "Expecting that {} + {} overflows", a, a);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let a: u8 = 100;
+///
+/// assert_overflows!(a.checked_mul(2));  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_overflows!`]: ./macro.debug_assert_overflows.html
+#[macro_export]
+macro_rules! assert_overflows {
+    (@fail $fail:tt $($arg:tt)+) => {
+        $crate::__assert_overflow!(@fail $fail @split overflows [] $($arg)+)
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_overflows!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that the checked arithmetic operation overflows in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_overflows!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_overflows!`]: ./macro.assert_overflows.html
+#[macro_export]
+macro_rules! debug_assert_overflows {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_overflows!($($arg)*); })
+}
+
+/// Implementation of the `assert_overflows!` and `assert_no_overflow!` macros.
+///
+/// Tokens are split at the first top-level comma into the operation and the custom message,
+/// and then the operation is munched to find the trailing `.method()` or `.method(argument)` call.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_overflow {
+    // Splits the operation and the custom message.
+    (@fail $fail:tt @split $mode:ident [$($op:tt)*] , $($msg:tt)+) => {
+        $crate::__assert_overflow!(@fail $fail @method $mode [$($op)*] [$($msg)+] [] $($op)*)
+    };
+    (@fail $fail:tt @split $mode:ident [$($op:tt)*] ,) => {
+        $crate::__assert_overflow!(@fail $fail @method $mode [$($op)*] [] [] $($op)*)
+    };
+    (@fail $fail:tt @split $mode:ident [$($op:tt)*]) => {
+        $crate::__assert_overflow!(@fail $fail @method $mode [$($op)*] [] [] $($op)*)
+    };
+    (@fail $fail:tt @split $mode:ident [$($op:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__assert_overflow!(@fail $fail @split $mode [$($op)* $next] $($rest)*)
+    };
+
+    // Finds the trailing method call with its receiver.
+    (@fail $fail:tt @method $mode:ident [$($op:tt)*] [$($msg:tt)*] [$($receiver:tt)+] . $method:ident ()) => {
+        match ($($receiver)+) {
+            value => {
+                use $crate::__private::integers_dispatch::*;
+
+                let result = value.$method();
+                $crate::__assert_overflow!(@fail $fail @check $mode [$($op)*] [$($msg)*] result, $crate::__private::Operands(&[
+                    ("value", (&$crate::__private::Operand(&value)).operand()),
+                ]))
+            }
+        }
+    };
+    // Closures are passed to the method as is, so their parameter types can be inferred.
+    (@fail $fail:tt @method $mode:ident [$($op:tt)*] [$($msg:tt)*] [$($receiver:tt)+] . $method:ident (| $($closure:tt)*)) => {
+        $crate::__assert_overflow!(@fail $fail @closure $mode [$($op)*] [$($msg)*] [$($receiver)+] $method (| $($closure)*))
+    };
+    (@fail $fail:tt @method $mode:ident [$($op:tt)*] [$($msg:tt)*] [$($receiver:tt)+] . $method:ident (|| $($closure:tt)*)) => {
+        $crate::__assert_overflow!(@fail $fail @closure $mode [$($op)*] [$($msg)*] [$($receiver)+] $method (|| $($closure)*))
+    };
+    (@fail $fail:tt @method $mode:ident [$($op:tt)*] [$($msg:tt)*] [$($receiver:tt)+] . $method:ident (move $($closure:tt)*)) => {
+        $crate::__assert_overflow!(@fail $fail @closure $mode [$($op)*] [$($msg)*] [$($receiver)+] $method (move $($closure)*))
+    };
+    (@fail $fail:tt @method $mode:ident [$($op:tt)*] [$($msg:tt)*] [$($receiver:tt)+] . $method:ident ($argument:expr)) => {
+        match ($($receiver)+, $argument) {
+            (left, right) => {
+                use $crate::__private::integers_dispatch::*;
+
+                let result = left.$method(right);
+                $crate::__assert_overflow!(@fail $fail @check $mode [$($op)*] [$($msg)*] result, $crate::__private::Operands(&[
+                    ("left", (&$crate::__private::Operand(&left)).operand()),
+                    ("right", (&$crate::__private::Operand(&right)).operand()),
+                ]))
+            }
+        }
+    };
+    (@fail $fail:tt @closure $mode:ident [$($op:tt)*] [$($msg:tt)*] [$($receiver:tt)+] $method:ident ($($closure:tt)*)) => {
+        match ($($receiver)+) {
+            left => {
+                use $crate::__private::integers_dispatch::*;
+
+                let result = left.$method($($closure)*);
+                $crate::__assert_overflow!(@fail $fail @check $mode [$($op)*] [$($msg)*] result, $crate::__private::Operands(&[
+                    ("left", (&$crate::__private::Operand(&left)).operand()),
+                ]))
+            }
+        }
+    };
+    (@fail $fail:tt @method $mode:ident [$($op:tt)*] [$($msg:tt)*] [$($receiver:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__assert_overflow!(@fail $fail @method $mode [$($op)*] [$($msg)*] [$($receiver)* $next] $($rest)*)
+    };
+    // Not a method call, so only the result is reported.
+    (@fail $fail:tt @method $mode:ident [$($op:tt)*] [$($msg:tt)*] [$($receiver:tt)*]) => {
+        match $($op)* {
+            result => $crate::__assert_overflow!(@fail $fail @check $mode [$($op)*] [$($msg)*] result, $crate::__private::Operands(&[]))
+        }
+    };
+
+    (@fail $fail:tt @check overflows [$($op:tt)*] [$($msg:tt)*] $result:ident, $operands:expr) => {
+        match $result {
+            None => (),
+            result @ Some(..) => {
+                $crate::__fail!($fail, "assert_overflows", "assertion failed, expected `{}` to overflow, got {:?}{}{}",
+                    stringify!($($op)*), result, $operands, $crate::__assert_overflow!(@fail $fail @custom [$($msg)*]));
+            }
+        }
+    };
+    (@fail $fail:tt @check no_overflow [$($op:tt)*] [$($msg:tt)*] $result:ident, $operands:expr) => {
+        match $result {
+            Some(value) => value,
+            None => {
+                $crate::__fail!($fail, "assert_no_overflow", "assertion failed, expected `{}` not to overflow, got None{}{}",
+                    stringify!($($op)*), $operands, $crate::__assert_overflow!(@fail $fail @custom [$($msg)*]));
+            }
+        }
+    };
+
+    (@fail $fail:tt @custom []) => {
+        ""
+    };
+    (@fail $fail:tt @custom [$($msg:tt)+]) => {
+        format_args!(": {}", format_args!($($msg)+))
+    };
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn overflows() {
+        let a: u8 = 200;
+        assert_overflows!(a.checked_mul(2));
+        assert_overflows!(i8::min_value().checked_neg(),);
+        assert_overflows!(
+            a.checked_add(100).and_then(|x| x.checked_mul(2)),
+            "custom {}",
+            "message"
+        );
+        assert_overflows!(a.checked_add(1).and_then(move |x| x.checked_mul(a)));
+        assert_overflows!(None::<u8>);
+        debug_assert_overflows!(a.checked_pow(2));
+    }
+
+    #[test]
+    fn evaluates_once() {
+        let mut calls = 0;
+        assert_overflows!({
+            calls += 1;
+            250u8
+        }
+        .checked_add({
+            calls += 1;
+            10
+        }));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected `a.checked_mul(2)` to overflow, got Some(200)
+    left: `100`,
+    right: `2`"
+    )]
+    fn default_panic_message() {
+        let a: u8 = 100;
+        assert_overflows!(a.checked_mul(2));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected `a.checked_neg()` to overflow, got Some(-5)
+    value: `5`: custom message"
+    )]
+    fn custom_panic_message() {
+        let a: i32 = 5;
+        assert_overflows!(a.checked_neg(), "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected `Some(1)` to overflow, got Some(1)")]
+    fn reports_other_expressions() {
+        assert_overflows!(Some(1));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected `a.checked_add(1).and_then(|x| x.checked_mul(2))` to overflow, got Some(4)
+    left: `Some(2)`"
+    )]
+    fn skips_opaque_operands() {
+        let a: u8 = 1;
+        assert_overflows!(a.checked_add(1).and_then(|x| x.checked_mul(2)));
+    }
+}
@@ -58,25 +58,28 @@
 /// [`debug_assert_pending!`]: ./macro.debug_assert_pending.html
 #[macro_export]
 macro_rules! assert_pending {
-    ($cond:expr,) => {
-        $crate::assert_pending!($cond);
+    (@fail $fail:tt $cond:expr,) => {
+        $crate::assert_pending!(@fail $fail $cond);
     };
-    ($cond:expr) => {
+    (@fail $fail:tt $cond:expr) => {
         match $cond {
             p @ core::task::Poll::Pending => p,
             r @ core::task::Poll::Ready(..) => {
-                panic!("assertion failed, expected Pending, got {:?}", r);
+                $crate::__fail!($fail, "assert_pending", "assertion failed, expected Pending, got {:?}", r);
             }
         }
     };
-    ($cond:expr, $($arg:tt)+) => {
+    (@fail $fail:tt $cond:expr, $($arg:tt)+) => {
         match $cond {
             p @ core::task::Poll::Pending => p,
             r @ core::task::Poll::Ready(..) => {
-                panic!("assertion failed, expected Pending, got {:?}", format_args!($($arg)+));
+                $crate::__fail!($fail, "assert_pending", "assertion failed, expected Pending, got {:?}", format_args!($($arg)+));
             }
         }
     };
+    ($($arg:tt)*) => {
+        $crate::assert_pending!(@fail [] $($arg)*)
+    };
 }
 
 /// Asserts that expression returns [`Poll::Pending`] variant in runtime.
@@ -0,0 +1,117 @@
+/// Asserts that the integer is a power of two.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Value can be of any primitive integer type. Zero and negative values are not powers of two.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_power_of_two!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let capacity: usize = 64;
+///
+/// assert_power_of_two!(capacity);
+///
+/// // With custom messages
+/// assert_power_of_two!(capacity, "Expecting that {} is a valid capacity", capacity);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let capacity: usize = 48;
+///
+/// assert_power_of_two!(capacity);  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_power_of_two!`]: ./macro.debug_assert_power_of_two.html
+#[macro_export]
+macro_rules! assert_power_of_two {
+    (@fail $fail:tt $value:expr,) => {
+        $crate::assert_power_of_two!(@fail $fail $value);
+    };
+    (@fail $fail:tt $value:expr) => {
+        match &$value {
+            value => {
+                if !$crate::__private::Integer::is_power_of_two(*value) {
+                    $crate::__fail!($fail, "assert_power_of_two", "assertion failed, expected power of two, got `{:?}`", value);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $value:expr, $($arg:tt)+) => {
+        match &$value {
+            value => {
+                if !$crate::__private::Integer::is_power_of_two(*value) {
+                    $crate::__fail!($fail, "assert_power_of_two", "assertion failed, expected power of two, got `{:?}`: {}", value, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_power_of_two!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that the integer is a power of two in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_power_of_two!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_power_of_two!`]: ./macro.assert_power_of_two.html
+#[macro_export]
+macro_rules! debug_assert_power_of_two {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_power_of_two!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn power_of_two() {
+        assert_power_of_two!(1u8);
+        assert_power_of_two!(1usize << 40,);
+        assert_power_of_two!(64i32, "custom {}", "message");
+        debug_assert_power_of_two!(1i128 << 126);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected power of two, got `48`")]
+    fn default_panic_message() {
+        assert_power_of_two!(48u32);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected power of two, got `0`: custom message")]
+    fn custom_panic_message() {
+        assert_power_of_two!(0u32, "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected power of two, got `-128`")]
+    fn negative() {
+        assert_power_of_two!(-128i8);
+    }
+}
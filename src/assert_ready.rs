@@ -55,25 +55,28 @@
 /// [`debug_assert_ready!`]: ./macro.debug_assert_ready.html
 #[macro_export]
 macro_rules! assert_ready {
-    ($cond:expr,) => {
-        $crate::assert_ready!($cond);
+    (@fail $fail:tt $cond:expr,) => {
+        $crate::assert_ready!(@fail $fail $cond);
     };
-    ($cond:expr) => {
+    (@fail $fail:tt $cond:expr) => {
         match $cond {
             core::task::Poll::Ready(t) => t,
             p @ core::task::Poll::Pending => {
-                panic!("assertion failed, expected Ready(..), got {:?}", p);
+                $crate::__fail!($fail, "assert_ready", "assertion failed, expected Ready(..), got {:?}", p);
             }
         }
     };
-    ($cond:expr, $($arg:tt)+) => {
+    (@fail $fail:tt $cond:expr, $($arg:tt)+) => {
         match $cond {
             core::task::Poll::Ready(t) => t,
             p @ core::task::Poll::Pending => {
-                panic!("assertion failed, expected Ready(..), got {:?}", format_args!($($arg)+));
+                $crate::__fail!($fail, "assert_ready", "assertion failed, expected Ready(..), got {:?}", format_args!($($arg)+));
             }
         }
     };
+    ($($arg:tt)*) => {
+        $crate::assert_ready!(@fail [] $($arg)*)
+    };
 }
 
 /// Asserts that expression returns [`Poll::Ready(T)`] variant in runtime.
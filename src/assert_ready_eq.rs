@@ -19,9 +19,12 @@
 /// # #[macro_use] extern crate claim;
 /// # use std::task::Poll;
 /// # fn main() {
-/// let res: Poll<Result<i32, ()>> = Poll::Ready(Ok(42));
+/// let res: Poll<i32> = Poll::Ready(42);
 ///
-/// assert_ready_eq!(res, Ok(42));
+/// assert_ready_eq!(res, 42);
+///
+/// // With custom messages
+/// assert_ready_eq!(res, 42, "Everything is good with {:?}", res);
 /// # }
 /// ```
 ///
@@ -31,10 +34,10 @@
 /// # #[macro_use] extern crate claim;
 /// # use std::task::Poll;
 /// # fn main() {
-/// let res: Poll<Result<i32, ()>> = Poll::Ready(Ok(42));
+/// let res: Poll<i32> = Poll::Ready(42);
 ///
-/// let value = assert_ready_eq!(res, Ok(42));
-/// assert_eq!(value, Ok(42));
+/// let value = assert_ready_eq!(res, 42);
+/// assert_eq!(value, 42);
 /// # }
 /// ```
 ///
@@ -44,43 +47,46 @@
 /// # #[macro_use] extern crate claim;
 /// # use std::task::Poll;
 /// # fn main() {
-/// let res: Poll<Result<i32, ()>> = Poll::Pending;
+/// let res: Poll<i32> = Poll::Pending;
 ///
-/// assert_ready_eq!(res, Ok(42));  // Will panic
+/// assert_ready_eq!(res, 42);  // Will panic
 /// # }
 /// ```
 ///
-/// [`Poll::Ready(Ok(T))`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`Poll::Ready(T)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
 /// [`Poll::Pending`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Pending
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_ready_eq!`]: ./macro.debug_assert_ready_eq.html
 #[macro_export]
 macro_rules! assert_ready_eq {
-    ($cond:expr, $expected:expr,) => {
-        $crate::assert_ready_ok_eq!($cond, $expected);
+    (@fail $fail:tt $cond:expr, $expected:expr,) => {
+        $crate::assert_ready_eq!(@fail $fail $cond, $expected)
     };
-    ($cond:expr, $expected:expr) => {
+    (@fail $fail:tt $cond:expr, $expected:expr) => {
         match $cond {
             core::task::Poll::Ready(t) => {
-                assert_eq!(t, $expected);
+                $crate::__assert_eq!($fail, "assert_ready_eq", t, $expected);
                 t
             },
-            err_or_pending => {
-                panic!("assertion failed, expected Ready(Ok(..)), got {:?}", err_or_pending);
+            p @ core::task::Poll::Pending => {
+                $crate::__fail!($fail, "assert_ready_eq", "assertion failed, expected Ready(..), got {:?}", p);
             }
         }
     };
-    ($cond:expr, $expected:expr, $($arg:tt)+) => {
+    (@fail $fail:tt $cond:expr, $expected:expr, $($arg:tt)+) => {
         match $cond {
             core::task::Poll::Ready(t) => {
-                assert_eq!(t, $expected, $($arg)+);
+                $crate::__assert_eq!($fail, "assert_ready_eq", t, $expected, $($arg)+);
                 t
             },
-            err_or_pending => {
-                panic!("assertion failed, expected Ready(Ok(..)), got {:?}: {}", err_or_pending, format_args!($($arg)+));
+            p @ core::task::Poll::Pending => {
+                $crate::__fail!($fail, "assert_ready_eq", "assertion failed, expected Ready(..), got {:?}: {}", p, format_args!($($arg)+));
             }
         }
     };
+    ($($arg:tt)*) => {
+        $crate::assert_ready_eq!(@fail [] $($arg)*)
+    };
 }
 
 /// Asserts that left expression returns [`Poll::Ready(T)`] variant
@@ -94,10 +100,61 @@ macro_rules! assert_ready_eq {
 /// See [`debug_assert!`] documentation for possible use cases.
 /// The same applies to this macro.
 ///
-/// [`Poll::Ready(Ok(T))`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`Poll::Ready(T)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
 /// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
 /// [`assert_ready_eq!`]: ./macro.assert_ready_eq.html
 #[macro_export]
-macro_rules! debug_assert_ready_ok_eq {
+macro_rules! debug_assert_ready_eq {
     ($($arg:tt)*) => (if core::cfg!(debug_assertions) { $crate::assert_ready_eq!($($arg)*); })
 }
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::task::Poll;
+
+    #[test]
+    fn returns_ready_value() {
+        let res: Poll<i32> = Poll::Ready(42);
+        assert_eq!(assert_ready_eq!(res, 42), 42);
+        assert_eq!(assert_ready_eq!(res, 42,), 42);
+        assert_eq!(assert_ready_eq!(res, 42, "custom {}", "message"), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ready(..), got Pending")]
+    fn default_panic_message() {
+        let res: Poll<i32> = Poll::Pending;
+        let _ = assert_ready_eq!(res, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ready(..), got Pending")]
+    fn trailing_comma_panic_message() {
+        let res: Poll<i32> = Poll::Pending;
+        let _ = assert_ready_eq!(res, 42,);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(..), got Pending: we are checking Pending"
+    )]
+    fn custom_panic_message() {
+        let res: Poll<i32> = Poll::Pending;
+        let _ = assert_ready_eq!(res, 42, "we are checking {:?}", res);
+    }
+
+    #[test]
+    #[should_panic(expected = "left == right")]
+    fn not_equal_panic_message() {
+        let res: Poll<i32> = Poll::Ready(41);
+        let _ = assert_ready_eq!(res, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "we are checking Ready(41)")]
+    fn not_equal_custom_panic_message() {
+        let res: Poll<i32> = Poll::Ready(41);
+        let _ = assert_ready_eq!(res, 42, "we are checking {:?}", res);
+    }
+}
@@ -49,7 +49,7 @@
 /// assert_ready_err!(res);  // Will panic
 /// # }
 /// ```
-
+///
 /// ```rust,should_panic
 /// # #[macro_use] extern crate claim;
 /// # use std::task::Poll;
@@ -66,25 +66,28 @@
 /// [`debug_assert_ready_err!`]: ./macro.debug_assert_ready_err.html
 #[macro_export]
 macro_rules! assert_ready_err {
-    ($cond:expr,) => {
-        $crate::assert_ready_err!($cond);
+    (@fail $fail:tt $cond:expr,) => {
+        $crate::assert_ready_err!(@fail $fail $cond);
     };
-    ($cond:expr) => {
+    (@fail $fail:tt $cond:expr) => {
         match $cond {
             core::task::Poll::Ready(Err(e)) => e,
             ok_or_pending => {
-                panic!("assertion failed, expected Ready(Err(..)), got {:?}", ok_or_pending);
+                $crate::__fail!($fail, "assert_ready_err", "assertion failed, expected Ready(Err(..)), got {:?}", ok_or_pending);
             }
         }
     };
-    ($cond:expr, $($arg:tt)+) => {
+    (@fail $fail:tt $cond:expr, $($arg:tt)+) => {
         match $cond {
             core::task::Poll::Ready(Err(e)) => e,
             ok_or_pending => {
-                panic!("assertion failed, expected Ready(Err(..)), got {:?}: {}", ok_or_pending, format_args!($($arg)+));
+                $crate::__fail!($fail, "assert_ready_err", "assertion failed, expected Ready(Err(..)), got {:?}: {}", ok_or_pending, format_args!($($arg)+));
             }
         }
     };
+    ($($arg:tt)*) => {
+        $crate::assert_ready_err!(@fail [] $($arg)*)
+    };
 }
 
 /// Asserts that expression returns [`Poll::Ready(Err(E))`] variant in runtime.
@@ -0,0 +1,170 @@
+/// Asserts that left expression returns [`Poll::Ready(Err(E))`] variant
+/// and its value of `E` type equals to the right expression.
+///
+/// This macro is available for Rust 1.36+.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ready_err_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<Result<(), i32>> = Poll::Ready(Err(42));
+///
+/// assert_ready_err_eq!(res, 42);
+///
+/// // With custom messages
+/// assert_ready_err_eq!(res, 42, "Everything is good with {:?}", res);
+/// # }
+/// ```
+///
+/// Value of `E` type from the `Poll::Ready(Err(E))` will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<Result<(), String>> = Poll::Ready(Err("Something went wrong".to_string()));
+///
+/// let message = assert_ready_err_eq!(res, "Something went wrong");
+/// assert_eq!("Something went wrong", message);
+/// # }
+/// ```
+///
+/// Both `Poll::Ready(Ok(..))` and [`Poll::Pending`] variants will cause panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<Result<(), i32>> = Poll::Ready(Ok(()));
+///
+/// assert_ready_err_eq!(res, 42);  // Will panic
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<Result<(), i32>> = Poll::Pending;
+///
+/// assert_ready_err_eq!(res, 42);  // Will panic
+/// # }
+/// ```
+///
+/// [`Poll::Ready(Err(E))`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`Poll::Pending`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Pending
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ready_err_eq!`]: ./macro.debug_assert_ready_err_eq.html
+#[macro_export]
+macro_rules! assert_ready_err_eq {
+    (@fail $fail:tt $cond:expr, $expected:expr,) => {
+        $crate::assert_ready_err_eq!(@fail $fail $cond, $expected)
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr) => {
+        match $cond {
+            core::task::Poll::Ready(Err(e)) => {
+                $crate::__assert_eq!($fail, "assert_ready_err_eq", e, $expected);
+                e
+            },
+            ok_or_pending => {
+                $crate::__fail!($fail, "assert_ready_err_eq", "assertion failed, expected Ready(Err(..)), got {:?}", ok_or_pending);
+            }
+        }
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr, $($arg:tt)+) => {
+        match $cond {
+            core::task::Poll::Ready(Err(e)) => {
+                $crate::__assert_eq!($fail, "assert_ready_err_eq", e, $expected, $($arg)+);
+                e
+            },
+            ok_or_pending => {
+                $crate::__fail!($fail, "assert_ready_err_eq", "assertion failed, expected Ready(Err(..)), got {:?}: {}", ok_or_pending, format_args!($($arg)+));
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_ready_err_eq!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that left expression returns [`Poll::Ready(Err(E))`] variant
+/// and its value of `E` type equals to the right expression in runtime.
+///
+/// Like [`assert_ready_err_eq!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Poll::Ready(Err(E))`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_ready_err_eq!`]: ./macro.assert_ready_err_eq.html
+#[macro_export]
+macro_rules! debug_assert_ready_err_eq {
+    ($($arg:tt)*) => (if core::cfg!(debug_assertions) { $crate::assert_ready_err_eq!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::task::Poll;
+
+    #[test]
+    fn returns_err_value() {
+        let res: Poll<Result<(), i32>> = Poll::Ready(Err(42));
+        assert_eq!(assert_ready_err_eq!(res, 42), 42);
+        assert_eq!(assert_ready_err_eq!(res, 42,), 42);
+        assert_eq!(assert_ready_err_eq!(res, 42, "custom {}", "message"), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ready(Err(..)), got Ready(Ok(()))")]
+    fn default_panic_message() {
+        let res: Poll<Result<(), i32>> = Poll::Ready(Ok(()));
+        let _ = assert_ready_err_eq!(res, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ready(Err(..)), got Pending")]
+    fn trailing_comma_panic_message() {
+        let res: Poll<Result<(), i32>> = Poll::Pending;
+        let _ = assert_ready_err_eq!(res, 42,);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(Err(..)), got Ready(Ok(())): we are checking Ready(Ok(()))"
+    )]
+    fn custom_panic_message() {
+        let res: Poll<Result<(), i32>> = Poll::Ready(Ok(()));
+        let _ = assert_ready_err_eq!(res, 42, "we are checking {:?}", res);
+    }
+
+    #[test]
+    #[should_panic(expected = "left == right")]
+    fn not_equal_panic_message() {
+        let res: Poll<Result<(), i32>> = Poll::Ready(Err(41));
+        let _ = assert_ready_err_eq!(res, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "we are checking Ready(Err(41))")]
+    fn not_equal_custom_panic_message() {
+        let res: Poll<Result<(), i32>> = Poll::Ready(Err(41));
+        let _ = assert_ready_err_eq!(res, 42, "we are checking {:?}", res);
+    }
+}
@@ -0,0 +1,156 @@
+/// Asserts that left expression returns [`Poll::Ready(T)`] variant
+/// and its value of `T` type does not equal to the right expression.
+///
+/// This macro is available for Rust 1.36+.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ready_ne!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<i32> = Poll::Ready(42);
+///
+/// assert_ready_ne!(res, 0);
+///
+/// // With custom messages
+/// assert_ready_ne!(res, 0, "Everything is good with {:?}", res);
+/// # }
+/// ```
+///
+/// Value of `T` type from the `Poll::Ready(T)` will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<i32> = Poll::Ready(42);
+///
+/// let value = assert_ready_ne!(res, 0);
+/// assert_eq!(value, 42);
+/// # }
+/// ```
+///
+/// Both [`Poll::Pending`] variant and equal values will cause panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<i32> = Poll::Pending;
+///
+/// assert_ready_ne!(res, 0);  // Will panic
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<i32> = Poll::Ready(0);
+///
+/// assert_ready_ne!(res, 0);  // Will panic
+/// # }
+/// ```
+///
+/// [`Poll::Ready(T)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`Poll::Pending`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Pending
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ready_ne!`]: ./macro.debug_assert_ready_ne.html
+#[macro_export]
+macro_rules! assert_ready_ne {
+    (@fail $fail:tt $cond:expr, $expected:expr,) => {
+        $crate::assert_ready_ne!(@fail $fail $cond, $expected)
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr) => {
+        match $cond {
+            core::task::Poll::Ready(t) => {
+                $crate::__assert_ne!($fail, "assert_ready_ne", t, $expected);
+                t
+            },
+            p @ core::task::Poll::Pending => {
+                $crate::__fail!($fail, "assert_ready_ne", "assertion failed, expected Ready(..), got {:?}", p);
+            }
+        }
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr, $($arg:tt)+) => {
+        match $cond {
+            core::task::Poll::Ready(t) => {
+                $crate::__assert_ne!($fail, "assert_ready_ne", t, $expected, $($arg)+);
+                t
+            },
+            p @ core::task::Poll::Pending => {
+                $crate::__fail!($fail, "assert_ready_ne", "assertion failed, expected Ready(..), got {:?}: {}", p, format_args!($($arg)+));
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_ready_ne!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that left expression returns [`Poll::Ready(T)`] variant
+/// and its value of `T` type does not equal to the right expression in runtime.
+///
+/// Like [`assert_ready_ne!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Poll::Ready(T)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_ready_ne!`]: ./macro.assert_ready_ne.html
+#[macro_export]
+macro_rules! debug_assert_ready_ne {
+    ($($arg:tt)*) => (if core::cfg!(debug_assertions) { $crate::assert_ready_ne!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::task::Poll;
+
+    #[test]
+    fn returns_ready_value() {
+        let res: Poll<i32> = Poll::Ready(42);
+        assert_eq!(assert_ready_ne!(res, 0), 42);
+        assert_eq!(assert_ready_ne!(res, 0,), 42);
+        assert_eq!(assert_ready_ne!(res, 0, "custom {}", "message"), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ready(..), got Pending")]
+    fn default_panic_message() {
+        let res: Poll<i32> = Poll::Pending;
+        let _ = assert_ready_ne!(res, 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(..), got Pending: we are checking Pending"
+    )]
+    fn custom_panic_message() {
+        let res: Poll<i32> = Poll::Pending;
+        let _ = assert_ready_ne!(res, 0, "we are checking {:?}", res);
+    }
+
+    #[test]
+    #[should_panic(expected = "left != right")]
+    fn equal_panic_message() {
+        let res: Poll<i32> = Poll::Ready(0);
+        let _ = assert_ready_ne!(res, 0);
+    }
+}
@@ -65,25 +65,28 @@
 /// [`debug_assert_ready_ok!`]: ./macro.debug_assert_ready_ok.html
 #[macro_export]
 macro_rules! assert_ready_ok {
-    ($cond:expr,) => {
-        $crate::assert_ready_ok!($cond);
+    (@fail $fail:tt $cond:expr,) => {
+        $crate::assert_ready_ok!(@fail $fail $cond);
     };
-    ($cond:expr) => {
+    (@fail $fail:tt $cond:expr) => {
         match $cond {
             core::task::Poll::Ready(Ok(t)) => t,
             err_or_pending => {
-                panic!("assertion failed, expected Ready(Ok(..)), got {:?}", err_or_pending);
+                $crate::__fail!($fail, "assert_ready_ok", "assertion failed, expected Ready(Ok(..)), got {:?}", err_or_pending);
             }
         }
     };
-    ($cond:expr, $($arg:tt)+) => {
+    (@fail $fail:tt $cond:expr, $($arg:tt)+) => {
         match $cond {
             core::task::Poll::Ready(Ok(t)) => t,
             err_or_pending => {
-                panic!("assertion failed, expected Ready(Ok(..)), got {:?}: {}", err_or_pending, format_args!($($arg)+));
+                $crate::__fail!($fail, "assert_ready_ok", "assertion failed, expected Ready(Ok(..)), got {:?}: {}", err_or_pending, format_args!($($arg)+));
             }
         }
     };
+    ($($arg:tt)*) => {
+        $crate::assert_ready_ok!(@fail [] $($arg)*)
+    };
 }
 
 /// Asserts that expression returns [`Poll::Ready(Ok(T))`] variant in runtime.
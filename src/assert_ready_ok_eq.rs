@@ -0,0 +1,170 @@
+/// Asserts that left expression returns [`Poll::Ready(Ok(T))`] variant
+/// and its value of `T` type equals to the right expression.
+///
+/// This macro is available for Rust 1.36+.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ready_ok_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<Result<i32, ()>> = Poll::Ready(Ok(42));
+///
+/// assert_ready_ok_eq!(res, 42);
+///
+/// // With custom messages
+/// assert_ready_ok_eq!(res, 42, "Everything is good with {:?}", res);
+/// # }
+/// ```
+///
+/// Value of `T` type from the `Poll::Ready(Ok(T))` will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<Result<i32, ()>> = Poll::Ready(Ok(42));
+///
+/// let value = assert_ready_ok_eq!(res, 42);
+/// assert_eq!(value, 42);
+/// # }
+/// ```
+///
+/// Both `Poll::Ready(Err(..))` and [`Poll::Pending`] variants will cause panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<Result<i32, ()>> = Poll::Ready(Err(()));
+///
+/// assert_ready_ok_eq!(res, 42);  // Will panic
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<Result<i32, ()>> = Poll::Pending;
+///
+/// assert_ready_ok_eq!(res, 42);  // Will panic
+/// # }
+/// ```
+///
+/// [`Poll::Ready(Ok(T))`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`Poll::Pending`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Pending
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ready_ok_eq!`]: ./macro.debug_assert_ready_ok_eq.html
+#[macro_export]
+macro_rules! assert_ready_ok_eq {
+    (@fail $fail:tt $cond:expr, $expected:expr,) => {
+        $crate::assert_ready_ok_eq!(@fail $fail $cond, $expected)
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr) => {
+        match $cond {
+            core::task::Poll::Ready(Ok(t)) => {
+                $crate::__assert_eq!($fail, "assert_ready_ok_eq", t, $expected);
+                t
+            },
+            err_or_pending => {
+                $crate::__fail!($fail, "assert_ready_ok_eq", "assertion failed, expected Ready(Ok(..)), got {:?}", err_or_pending);
+            }
+        }
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr, $($arg:tt)+) => {
+        match $cond {
+            core::task::Poll::Ready(Ok(t)) => {
+                $crate::__assert_eq!($fail, "assert_ready_ok_eq", t, $expected, $($arg)+);
+                t
+            },
+            err_or_pending => {
+                $crate::__fail!($fail, "assert_ready_ok_eq", "assertion failed, expected Ready(Ok(..)), got {:?}: {}", err_or_pending, format_args!($($arg)+));
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_ready_ok_eq!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that left expression returns [`Poll::Ready(Ok(T))`] variant
+/// and its value of `T` type equals to the right expression in runtime.
+///
+/// Like [`assert_ready_ok_eq!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Poll::Ready(Ok(T))`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_ready_ok_eq!`]: ./macro.assert_ready_ok_eq.html
+#[macro_export]
+macro_rules! debug_assert_ready_ok_eq {
+    ($($arg:tt)*) => (if core::cfg!(debug_assertions) { $crate::assert_ready_ok_eq!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::task::Poll;
+
+    #[test]
+    fn returns_ok_value() {
+        let res: Poll<Result<i32, ()>> = Poll::Ready(Ok(42));
+        assert_eq!(assert_ready_ok_eq!(res, 42), 42);
+        assert_eq!(assert_ready_ok_eq!(res, 42,), 42);
+        assert_eq!(assert_ready_ok_eq!(res, 42, "custom {}", "message"), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ready(Ok(..)), got Ready(Err(()))")]
+    fn default_panic_message() {
+        let res: Poll<Result<i32, ()>> = Poll::Ready(Err(()));
+        let _ = assert_ready_ok_eq!(res, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ready(Ok(..)), got Pending")]
+    fn trailing_comma_panic_message() {
+        let res: Poll<Result<i32, ()>> = Poll::Pending;
+        let _ = assert_ready_ok_eq!(res, 42,);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Ready(Ok(..)), got Ready(Err(())): we are checking Ready(Err(()))"
+    )]
+    fn custom_panic_message() {
+        let res: Poll<Result<i32, ()>> = Poll::Ready(Err(()));
+        let _ = assert_ready_ok_eq!(res, 42, "we are checking {:?}", res);
+    }
+
+    #[test]
+    #[should_panic(expected = "left == right")]
+    fn not_equal_panic_message() {
+        let res: Poll<Result<i32, ()>> = Poll::Ready(Ok(41));
+        let _ = assert_ready_ok_eq!(res, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "we are checking Ready(Ok(41))")]
+    fn not_equal_custom_panic_message() {
+        let res: Poll<Result<i32, ()>> = Poll::Ready(Ok(41));
+        let _ = assert_ready_ok_eq!(res, 42, "we are checking {:?}", res);
+    }
+}
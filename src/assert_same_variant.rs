@@ -0,0 +1,154 @@
+/// Asserts that both enum values are of the same variant.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Variants are compared with [`core::mem::discriminant`], so variant fields
+/// are ignored and are not required to implement [`PartialEq`].
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_same_variant!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// #[derive(Debug)]
+/// enum Event {
+///     Received { id: u32, payload: &'static str },
+///     Closed,
+/// }
+///
+/// let expected = Event::Received { id: 1, payload: "ping" };
+/// let actual = Event::Received { id: 2, payload: "pong" };
+///
+/// assert_same_variant!(actual, expected);
+///
+/// // With custom messages
+/// assert_same_variant!(actual, expected, "Unexpected event {:?}", actual);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// #[derive(Debug)]
+/// enum Event {
+///     Received { id: u32, payload: &'static str },
+///     Closed,
+/// }
+///
+/// let expected = Event::Received { id: 1, payload: "ping" };
+///
+/// assert_same_variant!(Event::Closed, expected);  // Will panic
+/// # }
+/// ```
+///
+/// [`core::mem::discriminant`]: https://doc.rust-lang.org/core/mem/fn.discriminant.html
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_same_variant!`]: ./macro.debug_assert_same_variant.html
+#[macro_export]
+macro_rules! assert_same_variant {
+    (@fail $fail:tt $left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if $crate::__private::discriminant(left_val) != $crate::__private::discriminant(right_val) {
+                    $crate::__fail!($fail, "assert_same_variant", r#"assertion failed: `(left variant == right variant)`
+    left: `{:?}`,
+    right: `{:?}`"#, &*left_val, &*right_val)
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $left:expr, $right:expr,) => {
+        $crate::assert_same_variant!(@fail $fail $left, $right);
+    };
+    (@fail $fail:tt $left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if $crate::__private::discriminant(left_val) != $crate::__private::discriminant(right_val) {
+                    $crate::__fail!($fail, "assert_same_variant", r#"assertion failed: `(left variant == right variant)`
+    left: `{:?}`,
+    right: `{:?}`: {}"#, &*left_val, &*right_val, format_args!($($arg)+))
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_same_variant!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that both enum values are of the same variant in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_same_variant!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_same_variant!`]: ./macro.assert_same_variant.html
+#[macro_export]
+macro_rules! debug_assert_same_variant {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_same_variant!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    enum Event {
+        Received { id: u32 },
+        Sent(u32),
+    }
+
+    impl Event {
+        fn id(&self) -> Option<u32> {
+            match *self {
+                Event::Received { id } | Event::Sent(id) => Some(id),
+            }
+        }
+    }
+
+    #[test]
+    fn same_variant() {
+        let event = Event::Received { id: 1 };
+        assert_same_variant!(
+            event,
+            Event::Received { id: 2 },
+            "checking {:?}",
+            event.id()
+        );
+        assert_same_variant!(Event::Sent(1), Event::Sent(2),);
+        assert_same_variant!(Some(1), Some(2), "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left variant == right variant)`
+    left: `Received { id: 1 }`,
+    right: `Sent(1)`"#)]
+    fn default_panic_message() {
+        assert_same_variant!(Event::Received { id: 1 }, Event::Sent(1));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left variant == right variant)`
+    left: `Some(1)`,
+    right: `None`: checking 1"#)]
+    fn custom_panic_message() {
+        assert_same_variant!(Some(1), None, "checking {}", 1);
+    }
+}
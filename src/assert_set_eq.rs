@@ -0,0 +1,149 @@
+/// Asserts that left and right expressions contain the same elements.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Both expressions can be arrays, slices, collections or `Clone` iterators,
+/// elements are compared with [`PartialEq`] and their order or duplicates do not matter.
+/// If any of expressions is a [`BTreeSet`] (with `alloc` feature) or a [`HashSet`] (with `std` feature),
+/// elements are looked up in it instead of comparing with each one.
+///
+/// On failure, missing elements (present in the right expression only)
+/// and extra elements (present in the left expression only) are reported.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_set_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let permissions = vec!["read", "write"];
+///
+/// assert_set_eq!(permissions, ["write", "read", "read"]);
+///
+/// // With custom messages
+/// assert_set_eq!(permissions, ["write", "read"], "Unexpected permissions in {:?}", permissions);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let permissions = vec!["read", "write"];
+///
+/// assert_set_eq!(permissions, ["read", "exec"]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`BTreeSet`]: https://doc.rust-lang.org/alloc/collections/btree_set/struct.BTreeSet.html
+/// [`HashSet`]: https://doc.rust-lang.org/std/collections/struct.HashSet.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_set_eq!`]: ./macro.debug_assert_set_eq.html
+#[macro_export]
+macro_rules! assert_set_eq {
+    (@fail $fail:tt $left:expr, $right:expr,) => {
+        $crate::assert_set_eq!(@fail $fail $left, $right);
+    };
+    (@fail $fail:tt $left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                use $crate::__private::sets_dispatch::*;
+
+                let missing = $crate::__private::Filtered::not_contained(
+                    (&$crate::__private::Items(right_val)).items(),
+                    (&$crate::__private::Members(left_val)).members((&$crate::__private::Items(left_val)).items()),
+                );
+                let extra = $crate::__private::Filtered::not_contained(
+                    (&$crate::__private::Items(left_val)).items(),
+                    (&$crate::__private::Members(right_val)).members((&$crate::__private::Items(right_val)).items()),
+                );
+                if !missing.is_empty() || !extra.is_empty() {
+                    $crate::__fail!($fail, "assert_set_eq", r#"assertion failed: `(left set == right set)`{}"#, $crate::__private::set_diff(&missing, &extra));
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                use $crate::__private::sets_dispatch::*;
+
+                let missing = $crate::__private::Filtered::not_contained(
+                    (&$crate::__private::Items(right_val)).items(),
+                    (&$crate::__private::Members(left_val)).members((&$crate::__private::Items(left_val)).items()),
+                );
+                let extra = $crate::__private::Filtered::not_contained(
+                    (&$crate::__private::Items(left_val)).items(),
+                    (&$crate::__private::Members(right_val)).members((&$crate::__private::Items(right_val)).items()),
+                );
+                if !missing.is_empty() || !extra.is_empty() {
+                    $crate::__fail!($fail, "assert_set_eq", r#"assertion failed: `(left set == right set)`{}: {}"#, $crate::__private::set_diff(&missing, &extra), format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_set_eq!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that left and right expressions contain the same elements in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_set_eq!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_set_eq!`]: ./macro.assert_set_eq.html
+#[macro_export]
+macro_rules! debug_assert_set_eq {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_set_eq!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_equal_sets() {
+        assert_set_eq!([1, 2, 2], [2, 1]);
+        assert_set_eq!([0; 0], [0; 0],);
+        assert_set_eq!([1], [1, 1], "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left set == right set)`
+    missing elements: [4],
+    extra elements: [1, 3]"#)]
+    fn default_panic_message() {
+        assert_set_eq!([1, 2, 3], [2, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left set == right set)`
+    missing elements: [4]: checking 4"#)]
+    fn custom_panic_message() {
+        assert_set_eq!([1], [1, 4], "checking {}", 4);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left set == right set)`
+    extra elements: [4]"#)]
+    fn extra_panic_message() {
+        assert_set_eq!([1, 4], [1]);
+    }
+}
@@ -40,25 +40,28 @@
 /// [`debug_assert_some!`]: ./macro.debug_assert_some.html
 #[macro_export]
 macro_rules! assert_some {
-    ($cond:expr,) => {
-        $crate::assert_some!($cond,);
+    (@fail $fail:tt $cond:expr,) => {
+        $crate::assert_some!(@fail $fail $cond,);
     };
-    ($cond:expr) => {
+    (@fail $fail:tt $cond:expr) => {
         match $cond {
             Some(t) => t,
             None => {
-                panic!("assertion failed, expected Some(..), got None");
+                $crate::__fail!($fail, "assert_some", "assertion failed, expected Some(..), got None");
             }
         }
     };
-    ($cond:expr, $($arg:tt)+) => {
+    (@fail $fail:tt $cond:expr, $($arg:tt)+) => {
         match $cond {
             Some(t) => t,
             None => {
-                panic!("assertion failed, expected Some(..), got None: {}", format_args!($($arg)+));
+                $crate::__fail!($fail, "assert_some", "assertion failed, expected Some(..), got None: {}", format_args!($($arg)+));
             }
         }
     };
+    ($($arg:tt)*) => {
+        $crate::assert_some!(@fail [] $($arg)*)
+    };
 }
 
 /// Asserts that expression returns [`Some(T)`] variant in runtime.
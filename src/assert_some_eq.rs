@@ -41,31 +41,34 @@
 /// [`debug_assert_some_eq!`]: ./macro.debug_assert_some_eq.html
 #[macro_export]
 macro_rules! assert_some_eq {
-    ($cond:expr, $expected:expr,) => {
-        $crate::assert_some_eq!($cond, $expected);
+    (@fail $fail:tt $cond:expr, $expected:expr,) => {
+        $crate::assert_some_eq!(@fail $fail $cond, $expected);
     };
-    ($cond:expr, $expected:expr) => {
+    (@fail $fail:tt $cond:expr, $expected:expr) => {
         match $cond {
             Some(t) => {
-                assert_eq!(t, $expected);
+                $crate::__assert_eq!($fail, "assert_some_eq", t, $expected);
                 t
             },
             None => {
-                panic!("assertion failed, expected Some(..), got None");
+                $crate::__fail!($fail, "assert_some_eq", "assertion failed, expected Some(..), got None");
             }
         }
     };
-    ($cond:expr, $expected:expr, $($arg:tt)+) => {
+    (@fail $fail:tt $cond:expr, $expected:expr, $($arg:tt)+) => {
         match $cond {
             Some(t) => {
-                assert_eq!(t, $expected);
+                $crate::__assert_eq!($fail, "assert_some_eq", t, $expected);
                 t
             },
             None => {
-                panic!("assertion failed, expected Some(..), got None: {}", format_args!($($arg)+));
+                $crate::__fail!($fail, "assert_some_eq", "assertion failed, expected Some(..), got None: {}", format_args!($($arg)+));
             }
         }
     };
+    ($($arg:tt)*) => {
+        $crate::assert_some_eq!(@fail [] $($arg)*)
+    };
 }
 
 /// Asserts that expression returns [`Some(T)`] variant in runtime.
@@ -0,0 +1,147 @@
+/// Asserts that left expression returns [`Some(T)`] variant
+/// and its value of `T` type does not equal to the right expression.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_some_ne!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let maybe = Some(42);
+///
+/// assert_some_ne!(maybe, 0);
+///
+/// // With custom messages
+/// assert_some_ne!(maybe, 0, "Got some value");
+/// # }
+/// ```
+///
+/// Value of `T` type from `Some(T)` will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let maybe = Some(42);
+///
+/// let value = assert_some_ne!(maybe, 0);
+/// assert_eq!(value, 42);
+/// # }
+/// ```
+///
+/// Both `None` variant and equal values will cause panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let maybe: Option<i32> = None;
+///
+/// assert_some_ne!(maybe, 0);  // Will panic
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let maybe = Some(0);
+///
+/// assert_some_ne!(maybe, 0);  // Will panic
+/// # }
+/// ```
+///
+/// [`Some(T)`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_some_ne!`]: ./macro.debug_assert_some_ne.html
+#[macro_export]
+macro_rules! assert_some_ne {
+    (@fail $fail:tt $cond:expr, $expected:expr,) => {
+        $crate::assert_some_ne!(@fail $fail $cond, $expected)
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr) => {
+        match $cond {
+            Some(t) => {
+                $crate::__assert_ne!($fail, "assert_some_ne", t, $expected);
+                t
+            },
+            None => {
+                $crate::__fail!($fail, "assert_some_ne", "assertion failed, expected Some(..), got None");
+            }
+        }
+    };
+    (@fail $fail:tt $cond:expr, $expected:expr, $($arg:tt)+) => {
+        match $cond {
+            Some(t) => {
+                $crate::__assert_ne!($fail, "assert_some_ne", t, $expected, $($arg)+);
+                t
+            },
+            None => {
+                $crate::__fail!($fail, "assert_some_ne", "assertion failed, expected Some(..), got None: {}", format_args!($($arg)+));
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_some_ne!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that left expression returns [`Some(T)`] variant
+/// and its value of `T` type does not equal to the right expression in runtime.
+///
+/// Like [`assert_some_ne!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Some(T)`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_some_ne!`]: ./macro.assert_some_ne.html
+#[macro_export]
+macro_rules! debug_assert_some_ne {
+    ($($arg:tt)*) => (if core::cfg!(debug_assertions) { $crate::assert_some_ne!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn returns_some_value() {
+        let maybe = Some(42);
+        assert_eq!(assert_some_ne!(maybe, 0), 42);
+        assert_eq!(assert_some_ne!(maybe, 0,), 42);
+        assert_eq!(assert_some_ne!(maybe, 0, "custom {}", "message"), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Some(..), got None")]
+    fn default_panic_message() {
+        let maybe: Option<i32> = None;
+        let _ = assert_some_ne!(maybe, 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected Some(..), got None: we are checking None"
+    )]
+    fn custom_panic_message() {
+        let maybe: Option<i32> = None;
+        let _ = assert_some_ne!(maybe, 0, "we are checking {:?}", maybe);
+    }
+
+    #[test]
+    #[should_panic(expected = "left != right")]
+    fn equal_panic_message() {
+        let maybe = Some(0);
+        let _ = assert_some_ne!(maybe, 0);
+    }
+}
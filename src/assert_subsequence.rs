@@ -0,0 +1,175 @@
+/// Asserts that sequence contains the expected elements in the same order,
+/// possibly with other elements between them.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Both sequence and expected elements can be arrays, slices, collections or `Clone` iterators,
+/// elements are compared with [`PartialEq`].
+/// Alternatively, expected elements can be provided as a list of patterns
+/// after the `matches` keyword, same to the [`assert_matches!`] patterns.
+///
+/// On failure, the amount of matched expected elements and the index of the last match are reported.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_subsequence!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let events = vec!["connect", "auth", "send", "close"];
+///
+/// assert_subsequence!(events, ["connect", "close"]);
+///
+/// // With custom messages
+/// assert_subsequence!(events, ["connect", "close"], "Checking {:?}", events);
+/// # }
+/// ```
+///
+/// Patterns can be used instead of the expected values:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let events = [Some(1), None, Some(3)];
+///
+/// assert_subsequence!(events, matches [Some(_), Some(_)]);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let events = vec!["connect", "auth", "send", "close"];
+///
+/// assert_subsequence!(events, ["close", "auth"]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_subsequence!`]: ./macro.debug_assert_subsequence.html
+#[macro_export]
+macro_rules! assert_subsequence {
+    (@fail $fail:tt $seq:expr, matches [$($pat:pat),+ $(,)*]) => {
+        $crate::__assert_sequence!(@fail $fail @patterns subsequence, "contains subsequence", $seq, [$($pat),+];);
+    };
+    (@fail $fail:tt $seq:expr, matches [$($pat:pat),+ $(,)*],) => {
+        $crate::__assert_sequence!(@fail $fail @patterns subsequence, "contains subsequence", $seq, [$($pat),+];);
+    };
+    (@fail $fail:tt $seq:expr, matches [$($pat:pat),+ $(,)*], $($arg:tt)+) => {
+        $crate::__assert_sequence!(@fail $fail @patterns subsequence, "contains subsequence", $seq, [$($pat),+]; $($arg)+);
+    };
+    (@fail $fail:tt $seq:expr, $expected:expr) => {
+        $crate::__assert_sequence!(@fail $fail @values subsequence, "contains subsequence", $seq, $expected;);
+    };
+    (@fail $fail:tt $seq:expr, $expected:expr,) => {
+        $crate::__assert_sequence!(@fail $fail @values subsequence, "contains subsequence", $seq, $expected;);
+    };
+    (@fail $fail:tt $seq:expr, $expected:expr, $($arg:tt)+) => {
+        $crate::__assert_sequence!(@fail $fail @values subsequence, "contains subsequence", $seq, $expected; $($arg)+);
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_subsequence!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that sequence contains the expected elements in the same order in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_subsequence!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_subsequence!`]: ./macro.assert_subsequence.html
+#[macro_export]
+macro_rules! debug_assert_subsequence {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_subsequence!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_subsequence() {
+        assert_subsequence!([1, 2, 3, 4], [1, 3, 4]);
+        assert_subsequence!([1, 2, 3], [0; 0],);
+        assert_subsequence!([1, 2][..], [1, 2], "custom {}", "message");
+        assert_subsequence!((1..10), (2..10).step_by(3));
+    }
+
+    #[test]
+    fn accepts_subsequence_patterns() {
+        assert_subsequence!([Some(1), None, Some(3)], matches [Some(1), Some(_)]);
+        assert_subsequence!([Some(1), None], matches [None,],);
+        assert_subsequence!([1, 2, 3], matches [1, 3], "custom {}", "message");
+    }
+
+    #[test]
+    fn accepts_irrefutable_patterns() {
+        assert_subsequence!([1, 2, 3], matches [_, 3]);
+        assert_subsequence!([1, 2, 3], matches [2, _x]);
+    }
+
+    #[test]
+    fn iterates_expected_values_once() {
+        use core::cell::Cell;
+        use core::ops::Range;
+
+        #[derive(Clone)]
+        struct Counted<'a>(Range<i32>, &'a Cell<usize>);
+
+        impl<'a> Iterator for Counted<'a> {
+            type Item = i32;
+
+            fn next(&mut self) -> Option<i32> {
+                self.1.set(self.1.get() + 1);
+                self.0.next()
+            }
+        }
+
+        let calls = Cell::new(0);
+        assert_subsequence!((0..100), Counted(90..100, &calls));
+        // A single pass over the expected values, including their end.
+        assert_eq!(calls.get(), 11);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left contains subsequence right)`
+    matched: 2 of 3 expected elements,
+    stopped at end of sequence: expected `2` after index 3"#)]
+    fn default_panic_message() {
+        assert_subsequence!([1, 2, 3, 4, 5], [1, 4, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left contains subsequence right)`
+    matched: 0 of 1 expected elements,
+    stopped at end of sequence: expected `3`: checking 3"#)]
+    fn custom_panic_message() {
+        assert_subsequence!([1, 2], [3], "checking {}", 3);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left contains subsequence right)`
+    matched: 1 of 2 expected elements,
+    stopped at end of sequence: expected `None` after index 0"#)]
+    fn patterns_panic_message() {
+        assert_subsequence!([Some(1), Some(2)], matches [Some(_), None]);
+    }
+}
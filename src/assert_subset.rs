@@ -0,0 +1,151 @@
+/// Asserts that all elements of the left expression are contained in the right expression.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Both expressions can be arrays, slices, collections or `Clone` iterators,
+/// elements are compared with [`PartialEq`] and their order or duplicates do not matter.
+/// If the right expression is a [`BTreeSet`] (with `alloc` feature) or a [`HashSet`] (with `std` feature),
+/// elements are looked up in it instead of comparing with each one.
+///
+/// Elements of the left expression, which are missing in the right one, are reported on failure.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_subset!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let permissions = vec!["read", "write"];
+///
+/// assert_subset!(permissions, ["read", "write", "exec"]);
+///
+/// // With custom messages
+/// assert_subset!(permissions, ["read", "write", "exec"], "Unexpected permissions in {:?}", permissions);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let permissions = vec!["read", "write"];
+///
+/// assert_subset!(permissions, ["read"]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`BTreeSet`]: https://doc.rust-lang.org/alloc/collections/btree_set/struct.BTreeSet.html
+/// [`HashSet`]: https://doc.rust-lang.org/std/collections/struct.HashSet.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_subset!`]: ./macro.debug_assert_subset.html
+#[macro_export]
+macro_rules! assert_subset {
+    (@fail $fail:tt $left:expr, $right:expr,) => {
+        $crate::assert_subset!(@fail $fail $left, $right);
+    };
+    (@fail $fail:tt $left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                use $crate::__private::sets_dispatch::*;
+
+                let extra = $crate::__private::Filtered::not_contained(
+                    (&$crate::__private::Items(left_val)).items(),
+                    (&$crate::__private::Members(right_val)).members((&$crate::__private::Items(right_val)).items()),
+                );
+                if !extra.is_empty() {
+                    $crate::__fail!($fail, "assert_subset", r#"assertion failed: `(left is subset of right)`
+    extra elements: {}"#, extra);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                use $crate::__private::sets_dispatch::*;
+
+                let extra = $crate::__private::Filtered::not_contained(
+                    (&$crate::__private::Items(left_val)).items(),
+                    (&$crate::__private::Members(right_val)).members((&$crate::__private::Items(right_val)).items()),
+                );
+                if !extra.is_empty() {
+                    $crate::__fail!($fail, "assert_subset", r#"assertion failed: `(left is subset of right)`
+    extra elements: {}: {}"#, extra, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_subset!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that all elements of the left expression are contained in the right expression in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_subset!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_subset!`]: ./macro.assert_subset.html
+#[macro_export]
+macro_rules! debug_assert_subset {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_subset!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_subsets() {
+        assert_subset!([1, 2], [3, 2, 1]);
+        assert_subset!([0; 0], [1],);
+        assert_subset!([1, 1], [1], "custom {}", "message");
+        assert_subset!((1..3), 0..4);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left is subset of right)`
+    extra elements: [4, 5]"#)]
+    fn default_panic_message() {
+        assert_subset!([1, 4, 2, 5], [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left is subset of right)`
+    extra elements: [4]: checking 4"#)]
+    fn custom_panic_message() {
+        assert_subset!([1, 4], [1], "checking {}", 4);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn looks_up_btree_set() {
+        let set: alloc::collections::BTreeSet<_> = [1, 2, 3].iter().cloned().collect();
+        assert_subset!([1, 3], set);
+        assert_subset!(set, [3, 2, 1]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "extra elements: [4]")]
+    fn looks_up_hash_set() {
+        let set: std::collections::HashSet<_> = [1, 2, 3].iter().cloned().collect();
+        assert_subset!([1, 4], set);
+    }
+}
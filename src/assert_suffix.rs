@@ -0,0 +1,146 @@
+/// Asserts that sequence ends with the expected elements.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Both sequence and expected elements can be arrays, slices, collections or `Clone` iterators,
+/// elements are compared with [`PartialEq`].
+/// Alternatively, expected elements can be provided as a list of patterns
+/// after the `matches` keyword, same to the [`assert_matches!`] patterns.
+///
+/// On failure, the amount of matched expected elements and the index,
+/// where the sequence stopped matching, are reported.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_suffix!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let events = vec!["connect", "auth", "send", "close"];
+///
+/// assert_suffix!(events, ["send", "close"]);
+///
+/// // With custom messages
+/// assert_suffix!(events, ["send", "close"], "Checking {:?}", events);
+/// # }
+/// ```
+///
+/// Patterns can be used instead of the expected values:
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let events = [Some(1), None, Some(3)];
+///
+/// assert_suffix!(events, matches [None, Some(_)]);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let events = vec!["connect", "auth", "send", "close"];
+///
+/// assert_suffix!(events, ["connect"]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_suffix!`]: ./macro.debug_assert_suffix.html
+#[macro_export]
+macro_rules! assert_suffix {
+    (@fail $fail:tt $seq:expr, matches [$($pat:pat),+ $(,)*]) => {
+        $crate::__assert_sequence!(@fail $fail @patterns suffix, "ends with", $seq, [$($pat),+];);
+    };
+    (@fail $fail:tt $seq:expr, matches [$($pat:pat),+ $(,)*],) => {
+        $crate::__assert_sequence!(@fail $fail @patterns suffix, "ends with", $seq, [$($pat),+];);
+    };
+    (@fail $fail:tt $seq:expr, matches [$($pat:pat),+ $(,)*], $($arg:tt)+) => {
+        $crate::__assert_sequence!(@fail $fail @patterns suffix, "ends with", $seq, [$($pat),+]; $($arg)+);
+    };
+    (@fail $fail:tt $seq:expr, $expected:expr) => {
+        $crate::__assert_sequence!(@fail $fail @values suffix, "ends with", $seq, $expected;);
+    };
+    (@fail $fail:tt $seq:expr, $expected:expr,) => {
+        $crate::__assert_sequence!(@fail $fail @values suffix, "ends with", $seq, $expected;);
+    };
+    (@fail $fail:tt $seq:expr, $expected:expr, $($arg:tt)+) => {
+        $crate::__assert_sequence!(@fail $fail @values suffix, "ends with", $seq, $expected; $($arg)+);
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_suffix!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that sequence ends with the expected elements in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_suffix!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_suffix!`]: ./macro.assert_suffix.html
+#[macro_export]
+macro_rules! debug_assert_suffix {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_suffix!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_suffix() {
+        assert_suffix!([1, 2, 3], [2, 3]);
+        assert_suffix!([1, 2, 3], [0; 0],);
+        assert_suffix!([1, 2][..], [1, 2], "custom {}", "message");
+        assert_suffix!((1..5), 3..5);
+    }
+
+    #[test]
+    fn accepts_suffix_patterns() {
+        assert_suffix!([Some(1), None, Some(3)], matches [None, Some(3)]);
+        assert_suffix!([Some(1), None], matches [None,],);
+        assert_suffix!([1, 2, 3], matches [2..=3, 3], "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left ends with right)`
+    matched: 1 of 2 expected elements,
+    stopped at index 2: expected `4`, got `3`"#)]
+    fn default_panic_message() {
+        assert_suffix!([1, 2, 3], [2, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left ends with right)`
+    matched: 0 of 3 expected elements,
+    sequence is too short: 2 elements: checking 3"#)]
+    fn custom_panic_message() {
+        assert_suffix!([1, 2], [1, 2, 3], "checking {}", 3);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left ends with right)`
+    matched: 0 of 1 expected elements,
+    stopped at index 1: expected `None`, got `Some(2)`"#)]
+    fn patterns_panic_message() {
+        assert_suffix!([Some(1), Some(2)], matches[None]);
+    }
+}
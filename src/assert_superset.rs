@@ -0,0 +1,134 @@
+/// Asserts that all elements of the right expression are contained in the left expression.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Both expressions can be arrays, slices, collections or `Clone` iterators,
+/// elements are compared with [`PartialEq`] and their order or duplicates do not matter.
+/// If the left expression is a [`BTreeSet`] (with `alloc` feature) or a [`HashSet`] (with `std` feature),
+/// elements are looked up in it instead of comparing with each one.
+///
+/// Elements of the right expression, which are missing in the left one, are reported on failure.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_superset!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let permissions = vec!["read", "write"];
+///
+/// assert_superset!(permissions, ["read"]);
+///
+/// // With custom messages
+/// assert_superset!(permissions, ["read"], "Not enough permissions in {:?}", permissions);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let permissions = vec!["read", "write"];
+///
+/// assert_superset!(permissions, ["read", "exec"]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`BTreeSet`]: https://doc.rust-lang.org/alloc/collections/btree_set/struct.BTreeSet.html
+/// [`HashSet`]: https://doc.rust-lang.org/std/collections/struct.HashSet.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_superset!`]: ./macro.debug_assert_superset.html
+#[macro_export]
+macro_rules! assert_superset {
+    (@fail $fail:tt $left:expr, $right:expr,) => {
+        $crate::assert_superset!(@fail $fail $left, $right);
+    };
+    (@fail $fail:tt $left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                use $crate::__private::sets_dispatch::*;
+
+                let missing = $crate::__private::Filtered::not_contained(
+                    (&$crate::__private::Items(right_val)).items(),
+                    (&$crate::__private::Members(left_val)).members((&$crate::__private::Items(left_val)).items()),
+                );
+                if !missing.is_empty() {
+                    $crate::__fail!($fail, "assert_superset", r#"assertion failed: `(left is superset of right)`
+    missing elements: {}"#, missing);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                use $crate::__private::sets_dispatch::*;
+
+                let missing = $crate::__private::Filtered::not_contained(
+                    (&$crate::__private::Items(right_val)).items(),
+                    (&$crate::__private::Members(left_val)).members((&$crate::__private::Items(left_val)).items()),
+                );
+                if !missing.is_empty() {
+                    $crate::__fail!($fail, "assert_superset", r#"assertion failed: `(left is superset of right)`
+    missing elements: {}: {}"#, missing, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_superset!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that all elements of the right expression are contained in the left expression in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_superset!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_superset!`]: ./macro.assert_superset.html
+#[macro_export]
+macro_rules! debug_assert_superset {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_superset!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_supersets() {
+        assert_superset!([3, 2, 1], [1, 2]);
+        assert_superset!([1], [0; 0],);
+        assert_superset!([1], [1, 1], "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left is superset of right)`
+    missing elements: [4, 5]"#)]
+    fn default_panic_message() {
+        assert_superset!([1, 2, 3], [1, 4, 2, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left is superset of right)`
+    missing elements: [4]: checking 4"#)]
+    fn custom_panic_message() {
+        assert_superset!([1], [1, 4], "checking {}", 4);
+    }
+}
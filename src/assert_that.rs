@@ -0,0 +1,140 @@
+/// Asserts that expression value is accepted by the given [matcher].
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Requires that the expression value implements [`Debug`].
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_that!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use claim::matchers::{contains, eq, gt, lt};
+/// # fn main() {
+///
+/// assert_that!(5, all_of![gt(3), lt(10)]);
+/// assert_that!(vec![1, 5, 7], contains(eq(5)));
+///
+/// // With custom messages
+/// assert_that!(5, gt(3), "Expecting that {} is greater than {}", 5, 3);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// use claim::matchers::{gt, lt};
+/// # fn main() {
+///
+/// assert_that!(12, all_of![gt(3), lt(10)]);  // Will panic
+/// # }
+/// ```
+///
+/// [matcher]: ./matchers/trait.Matcher.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_that!`]: ./macro.debug_assert_that.html
+#[macro_export]
+macro_rules! assert_that {
+    (@fail $fail:tt $value:expr, $matcher:expr,) => {
+        $crate::assert_that!(@fail $fail $value, $matcher);
+    };
+    (@fail $fail:tt $value:expr, $matcher:expr) => {
+        match (&$value, &$matcher) {
+            (value, matcher) => {
+                if !$crate::matchers::Matcher::matches(matcher, value) {
+                    $crate::__fail!($fail, "assert_that", r#"assertion failed: `(value matches expected)`
+    value: `{:?}`,
+    expected: {},
+    mismatch: {}"#, &*value, $crate::matchers::description(matcher, value), $crate::matchers::mismatch(matcher, value))
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $value:expr, $matcher:expr, $($arg:tt)+) => {
+        match (&$value, &$matcher) {
+            (value, matcher) => {
+                if !$crate::matchers::Matcher::matches(matcher, value) {
+                    $crate::__fail!($fail, "assert_that", r#"assertion failed: `(value matches expected)`
+    value: `{:?}`,
+    expected: {},
+    mismatch: {}: {}"#, &*value, $crate::matchers::description(matcher, value), $crate::matchers::mismatch(matcher, value), format_args!($($arg)+))
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_that!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that expression value is accepted by the given [matcher] in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_that!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [matcher]: ./matchers/trait.Matcher.html
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_that!`]: ./macro.assert_that.html
+#[macro_export]
+macro_rules! debug_assert_that {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_that!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use matchers::{contains, eq, ge, gt, len, lt, ne, not, starts_with};
+
+    #[test]
+    fn accepts_matching_values() {
+        assert_that!(5, ge(5));
+        assert_that!(5, all_of![gt(3), lt(10), ne(7)],);
+        assert_that!(0, any_of![eq(0), gt(10)]);
+        assert_that!([1, 5, 7], contains(eq(5)));
+        assert_that!("abcde", starts_with("ab").and(len(5)));
+        assert_that!("abcde", not(starts_with("b")), "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(value matches expected)`
+    value: `12`,
+    expected: greater than 3 and less than 10,
+    mismatch: was not less than 10"#)]
+    fn default_panic_message() {
+        assert_that!(12, all_of![gt(3), lt(10)]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(value matches expected)`
+    value: `12`,
+    expected: less than 10,
+    mismatch: was not less than 10: 12 is too big"#)]
+    fn custom_panic_message() {
+        assert_that!(12, lt(10), "{} is too big", 12);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(value matches expected)`
+    value: `[1, 2]`,
+    expected: containing an element equal to 5 or with length 3,
+    mismatch: had no element equal to 5 and had length 2"#)]
+    fn nested_panic_message() {
+        assert_that!([1, 2][..], contains(eq(5)).or(len(3)));
+    }
+}
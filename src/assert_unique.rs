@@ -0,0 +1,140 @@
+/// Asserts that all elements are unique.
+///
+/// This macro requires `alloc` feature and is available for Rust 1.31+.
+///
+/// Elements can be provided as an array, slice, collection or a `Clone` iterator.
+/// Elements are compared with [`Ord`] or, if `std` feature is enabled, with [`Hash`] and [`Eq`],
+/// whenever they implement these traits.
+///
+/// On failure, each duplicated value is reported with all indices of its elements.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_unique!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let ports = vec![80, 443, 8080];
+///
+/// assert_unique!(ports);
+///
+/// // With custom messages
+/// assert_unique!(ports, "Ports are reused in {:?}", ports);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let ports = vec![80, 443, 8080];
+///
+/// assert_unique!([80, 443, 80]);  // Will panic
+/// # }
+/// ```
+///
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`Hash`]: https://doc.rust-lang.org/core/hash/trait.Hash.html
+/// [`Eq`]: https://doc.rust-lang.org/core/cmp/trait.Eq.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_unique!`]: ./macro.debug_assert_unique.html
+#[macro_export]
+macro_rules! assert_unique {
+    (@fail $fail:tt $items:expr,) => {
+        $crate::assert_unique!(@fail $fail $items);
+    };
+    (@fail $fail:tt $items:expr) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+                use $crate::__private::unique_dispatch::*;
+
+                let keys = $crate::__private::keys((&$crate::__private::Items(items_val)).items(), |item| item);
+                let duplicates = (&keys).duplicates();
+                if !duplicates.is_empty() {
+                    $crate::__fail!($fail, "assert_unique", r#"assertion failed: `(all elements are unique)`{}"#, duplicates);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $items:expr, $($arg:tt)+) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+                use $crate::__private::unique_dispatch::*;
+
+                let keys = $crate::__private::keys((&$crate::__private::Items(items_val)).items(), |item| item);
+                let duplicates = (&keys).duplicates();
+                if !duplicates.is_empty() {
+                    $crate::__fail!($fail, "assert_unique", r#"assertion failed: `(all elements are unique)`{}: {}"#, duplicates, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_unique!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that all elements are unique in runtime.
+///
+/// This macro requires `alloc` feature and is available for Rust 1.31+.
+///
+/// Like [`assert_unique!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_unique!`]: ./macro.assert_unique.html
+#[macro_export]
+macro_rules! debug_assert_unique {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_unique!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_unique() {
+        assert_unique!([1, 2, 3]);
+        assert_unique!([0; 0],);
+        assert_unique!((1..4), "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(all elements are unique)`
+    `3` at indices [1, 4],
+    `1` at indices [2, 3, 5]"#)]
+    fn default_panic_message() {
+        assert_unique!([0, 3, 1, 1, 3, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(all elements are unique)`
+    `1` at indices [0, 1]: checking 1"#)]
+    fn custom_panic_message() {
+        assert_unique!([1, 1], "checking {}", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(all elements are unique)`
+    `Ordered(1)` at indices [0, 2]"#)]
+    fn ordered_elements() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Ordered(u8);
+
+        assert_unique!([Ordered(1), Ordered(2), Ordered(1)]);
+    }
+}
@@ -0,0 +1,143 @@
+/// Asserts that all elements have unique keys.
+///
+/// This macro requires `alloc` feature and is available for Rust 1.31+.
+///
+/// Elements can be provided as an array, slice, collection or a `Clone` iterator.
+/// Key is returned by the closure, which is called with each element.
+/// Keys are compared with [`Ord`] or, if `std` feature is enabled, with [`Hash`] and [`Eq`],
+/// whenever they implement these traits.
+///
+/// On failure, each duplicated key is reported with all indices of its elements.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_unique_by_key!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// #[derive(Debug)]
+/// struct User {
+///     id: u32,
+///     name: &'static str,
+/// }
+///
+/// let users = vec![User { id: 1, name: "root" }, User { id: 2, name: "root" }];
+///
+/// assert_unique_by_key!(users, |user| user.id);
+///
+/// // With custom messages
+/// assert_unique_by_key!(users, |user| user.id, "IDs are reused in {:?}", users);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// #[derive(Debug)]
+/// struct User {
+///     id: u32,
+///     name: &'static str,
+/// }
+///
+/// let users = vec![User { id: 1, name: "root" }, User { id: 2, name: "root" }];
+///
+/// assert_unique_by_key!(users, |user| user.name);  // Will panic
+/// # }
+/// ```
+///
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`Hash`]: https://doc.rust-lang.org/core/hash/trait.Hash.html
+/// [`Eq`]: https://doc.rust-lang.org/core/cmp/trait.Eq.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_unique_by_key!`]: ./macro.debug_assert_unique_by_key.html
+#[macro_export]
+macro_rules! assert_unique_by_key {
+    (@fail $fail:tt $items:expr, $key:expr,) => {
+        $crate::assert_unique_by_key!(@fail $fail $items, $key);
+    };
+    (@fail $fail:tt $items:expr, $key:expr) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+                use $crate::__private::unique_dispatch::*;
+
+                let keys = $crate::__private::keys((&$crate::__private::Items(items_val)).items(), $key);
+                let duplicates = (&keys).duplicates();
+                if !duplicates.is_empty() {
+                    $crate::__fail!($fail, "assert_unique_by_key", r#"assertion failed: `(all element keys are unique)`{}"#, duplicates);
+                }
+            }
+        }
+    };
+    (@fail $fail:tt $items:expr, $key:expr, $($arg:tt)+) => {
+        match &$items {
+            items_val => {
+                use $crate::__private::items_dispatch::*;
+                use $crate::__private::unique_dispatch::*;
+
+                let keys = $crate::__private::keys((&$crate::__private::Items(items_val)).items(), $key);
+                let duplicates = (&keys).duplicates();
+                if !duplicates.is_empty() {
+                    $crate::__fail!($fail, "assert_unique_by_key", r#"assertion failed: `(all element keys are unique)`{}: {}"#, duplicates, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_unique_by_key!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that all elements have unique keys in runtime.
+///
+/// This macro requires `alloc` feature and is available for Rust 1.31+.
+///
+/// Like [`assert_unique_by_key!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_unique_by_key!`]: ./macro.assert_unique_by_key.html
+#[macro_export]
+macro_rules! debug_assert_unique_by_key {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_unique_by_key!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn accepts_unique_keys() {
+        assert_unique_by_key!([(1, 'a'), (2, 'a')], |x| x.0);
+        assert_unique_by_key!([(1, 'a')], |x| x.1,);
+        assert_unique_by_key!((1..4), |x| x * 2, "custom {}", "message");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(all element keys are unique)`
+    `'a'` at indices [0, 2]"#)]
+    fn default_panic_message() {
+        assert_unique_by_key!([(1, 'a'), (2, 'b'), (3, 'a')], |x| x.1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(all element keys are unique)`
+    `0` at indices [0, 1],
+    `1` at indices [2, 3]: checking parity"#)]
+    fn custom_panic_message() {
+        assert_unique_by_key!([2, 4, 1, 3], |x| x % 2, "checking {}", "parity");
+    }
+}
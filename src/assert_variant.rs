@@ -0,0 +1,141 @@
+/// Asserts that enum value is of the given variant.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Unlike [`assert_matches!`], only the variant path is required,
+/// so unit, tuple and struct variants are checked without the `(..)` or `{ .. }` patterns.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_variant!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// #[derive(Debug)]
+/// enum Event {
+///     Received { id: u32, payload: &'static str },
+///     Sent(u32),
+///     Closed,
+/// }
+///
+/// let event = Event::Received { id: 1, payload: "ping" };
+///
+/// assert_variant!(event, Event::Received);
+/// assert_variant!(Event::Sent(1), Event::Sent);
+/// assert_variant!(Event::Closed, Event::Closed);
+///
+/// // With custom messages
+/// assert_variant!(event, Event::Received, "Unexpected event {:?}", event);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let res: Result<i32, ()> = Err(());
+///
+/// assert_variant!(res, Ok);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_variant!`]: ./macro.debug_assert_variant.html
+#[macro_export]
+macro_rules! assert_variant {
+    (@fail $fail:tt $value:expr, $variant:path) => {
+        match &$value {
+            $variant { .. } => {}
+            other => {
+                $crate::__fail!($fail, "assert_variant", "assertion failed, expected `{}` variant, got `{:?}`", stringify!($variant), other);
+            }
+        }
+    };
+    (@fail $fail:tt $value:expr, $variant:path,) => {
+        $crate::assert_variant!(@fail $fail $value, $variant);
+    };
+    (@fail $fail:tt $value:expr, $variant:path, $($arg:tt)+) => {
+        match &$value {
+            $variant { .. } => {}
+            other => {
+                $crate::__fail!($fail, "assert_variant", "assertion failed, expected `{}` variant, got `{:?}`: {}", stringify!($variant), other, format_args!($($arg)+));
+            }
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::assert_variant!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that enum value is of the given variant in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_variant!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_variant!`]: ./macro.assert_variant.html
+#[macro_export]
+macro_rules! debug_assert_variant {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_variant!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    enum Event {
+        Received { id: u32 },
+        Sent(u32),
+        Closed,
+    }
+
+    impl Event {
+        fn id(&self) -> Option<u32> {
+            match *self {
+                Event::Received { id } | Event::Sent(id) => Some(id),
+                Event::Closed => None,
+            }
+        }
+    }
+
+    #[test]
+    fn variant() {
+        let event = Event::Received { id: 1 };
+        assert_variant!(event, Event::Received, "checking {:?}", event.id());
+        assert_variant!(Event::Sent(1), Event::Sent,);
+        assert_variant!(Event::Closed, Event::Closed, "custom {}", "message");
+        assert_variant!(Some(1), Some);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected `Event::Sent` variant, got `Received { id: 1 }`"
+    )]
+    fn default_panic_message() {
+        assert_variant!(Event::Received { id: 1 }, Event::Sent);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected `Event::Closed` variant, got `Sent(1)`: checking 1"
+    )]
+    fn custom_panic_message() {
+        assert_variant!(Event::Sent(1), Event::Closed, "checking {}", 1);
+    }
+}
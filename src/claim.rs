@@ -0,0 +1,177 @@
+/// Asserts that boolean expression is `true`, showing values of its sub-expressions on failure.
+///
+/// This macro requires `power-assert` feature and is available for Rust 1.71+.
+///
+/// Expression is rewritten to record the values of variables, fields, method and function calls
+/// and operators, which are printed under the expression source in a power-assert style diagram.
+/// Values, which do not implement [`Debug`], are not shown.
+///
+/// Only the evaluated sub-expressions are recorded, so `&&` and `||` operators are still short-circuiting.
+/// Values of primitive types, such as integers and booleans, are formatted only on failure,
+/// but the other values are formatted on every evaluation, so this macro is slower than [`assert!`].
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_claim!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let a = [1, 2, 3];
+/// let offset = 1;
+/// let b = Vec::<u8>::with_capacity(8);
+///
+/// claim!(a.len() + offset < b.capacity());
+///
+/// // With custom messages
+/// claim!(a.len() + offset < b.capacity(), "Checking {:?}", a);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # fn main() {
+/// let a = [1, 2, 3];
+/// let offset = 2;
+/// let b = Vec::<u8>::with_capacity(4);
+///
+/// claim!(a.len() + offset < b.capacity());  // Will panic
+/// # }
+/// ```
+///
+/// Panic message for the failed assertion above:
+///
+/// ```text
+/// assertion failed: a.len() + offset < b.capacity()
+///                   | |     | |      | | |
+///                   | 3     5 2      | | 4
+///                   [1, 2, 3]        | []
+///                                    false
+/// ```
+///
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`assert!`]: https://doc.rust-lang.org/core/macro.assert.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_claim!`]: ./macro.debug_claim.html
+#[macro_export]
+macro_rules! claim {
+    (@fail $fail:tt $($arg:tt)+) => {
+        $crate::__private::claim!($crate, $fail; $($arg)+)
+    };
+    ($($arg:tt)*) => {
+        $crate::claim!(@fail [] $($arg)*)
+    };
+}
+
+/// Asserts that boolean expression is `true` in runtime,
+/// showing values of its sub-expressions on failure.
+///
+/// This macro requires `power-assert` feature and is available for Rust 1.71+.
+///
+/// Like [`claim!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`claim!`]: ./macro.claim.html
+#[macro_export]
+macro_rules! debug_claim {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::claim!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use alloc::vec::Vec;
+
+    struct Opaque(u32);
+
+    impl Opaque {
+        fn get(&self) -> u32 {
+            self.0
+        }
+    }
+
+    #[derive(Debug)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn passes() {
+        let a = [1, 2, 3];
+        let offset = 1;
+        let b = Vec::<u8>::with_capacity(8);
+        claim!(a.len() + offset < b.capacity());
+        claim!(a.len() + offset < b.capacity(),);
+        claim!(a[0] == 1 && !a.is_empty(), "checking {:?}", a);
+
+        let point = Point { x: 1, y: 2 };
+        claim!(point.x < point.y && point.x as i64 == 1);
+        claim!(Opaque(1).get() == 1 || point.x > 0);
+        let boxed = &point;
+        claim!((*boxed).x * 2 == point.y);
+    }
+
+    #[test]
+    fn does_not_move_values() {
+        let words = Vec::from(["a", "b"]);
+        claim!(words.len() == 2);
+        claim!(words == ["a", "b"]);
+        claim!(words[1] == "b");
+        assert_eq!(words.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: a.len() + offset < b.capacity()
+                  | |     | |      | | |
+                  | 3     5 2      | | 4
+                  [1, 2, 3]        | []
+                                   false"#)]
+    fn default_panic_message() {
+        let a = [1, 2, 3];
+        let offset = 2;
+        let b = Vec::<u8>::with_capacity(4);
+        claim!(a.len() + offset < b.capacity());
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: point.x > 0 && opaque.get() == 0
+                  |     | |   |         |     |
+                  |     1 |   false     1     false
+                  |       true
+                  Point { x: 1, y: 2 }: checking 1"#)]
+    fn custom_panic_message() {
+        let point = Point { x: 1, y: 2 };
+        let opaque = Opaque(1);
+        claim!(point.x > 0 && opaque.get() == 0, "checking {}", 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: letter == 'b' || offset > 0 || scale * 4.0 > 1.5
+                  |      |      |  |      |   |  |     |     |
+                  'a'    false  |  -3     |   |  0.25  1.0   false
+                                false     |   false
+                                          false"#
+    )]
+    fn primitive_values() {
+        let letter = 'a';
+        let scale = 0.25f32;
+        let offset: isize = -3;
+        claim!(letter == 'b' || offset > 0 || scale * 4.0 > 1.5);
+    }
+}
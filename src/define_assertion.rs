@@ -0,0 +1,283 @@
+/// Defines a family of custom assertion macros from a predicate and a message template.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// For the given names, it defines the following macros with the same conventions
+/// as the macros of this crate:
+///
+/// * assertion macro, which panics if the predicate returns `false`,
+///   with the trailing comma and custom message forms;
+/// * `debug_*` counterpart of the assertion macro,
+///   which is checked only if `debug_assertions` are enabled;
+/// * `check_*` macro, which returns the result of the predicate as `bool` instead of panicking.
+///
+/// Predicate is written as a closure, which parameters become the macro arguments.
+/// Every argument is evaluated only once and is bound by reference.
+/// The message template and its arguments can use the same parameters,
+/// and it is prefixed with `assertion failed, ` on panic.
+///
+/// Doc comments are applied to the assertion macro, and the `debug_*` and `check_*` macros
+/// get their own docs, which refer to it. Other attributes, such as `#[macro_export]`,
+/// are applied to every defined macro.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// #[derive(Debug)]
+/// struct Response {
+///     status: u16,
+/// }
+///
+/// define_assertion! {
+///     /// Asserts that the response has the expected status.
+///     assert_status, debug_assert_status, check_status;
+///     |response, status| response.status == *status,
+///     "expected status `{}`, got `{:?}`", status, response
+/// }
+///
+/// # fn main() {
+/// let response = Response { status: 200 };
+/// assert_status!(response, 200);
+/// debug_assert_status!(response, 200, "custom {}", "message");
+/// assert!(!check_status!(response, 404));
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claim;
+/// # #[derive(Debug)]
+/// # struct Response {
+/// #     status: u16,
+/// # }
+/// define_assertion! {
+///     assert_status, debug_assert_status, check_status;
+///     |response, status| response.status == *status,
+///     "expected status `{}`, got `{:?}`", status, response
+/// }
+///
+/// # fn main() {
+/// let response = Response { status: 500 };
+/// assert_status!(response, 200);  // Will panic
+/// # }
+/// ```
+#[macro_export]
+macro_rules! define_assertion {
+    (
+        $(#[$($attr:tt)*])*
+        $assert:ident, $debug:ident, $check:ident;
+        |$($param:ident),+| $predicate:expr,
+        $message:expr $(, $message_arg:expr)* $(,)*
+    ) => {
+        $crate::__define_assertion! {
+            @split ($) [] [] [$(#[$($attr)*])*]
+            $assert, $debug, $check;
+            [$($param),+] [$predicate]
+            [$message $(, $message_arg)*]
+        }
+    };
+}
+
+/// Implementation of the `define_assertion!` macro.
+///
+/// The `$` token is passed as the first argument,
+/// so it can be used for the metavariables of the defined macros.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_assertion {
+    // Doc comments are collected for the assertion macro only,
+    // the other attributes are applied to every defined macro.
+    (
+        @split ($d:tt) [$($doc:expr,)*] [$($attr:tt)*] [#[doc = $value:expr] $($rest:tt)*]
+        $($tail:tt)*
+    ) => {
+        $crate::__define_assertion! {
+            @split ($d) [$($doc,)* $value,] [$($attr)*] [$($rest)*]
+            $($tail)*
+        }
+    };
+    (
+        @split ($d:tt) [$($doc:expr,)*] [$($attr:tt)*] [#[$($meta:tt)*] $($rest:tt)*]
+        $($tail:tt)*
+    ) => {
+        $crate::__define_assertion! {
+            @split ($d) [$($doc,)*] [$($attr)* #[$($meta)*]] [$($rest)*]
+            $($tail)*
+        }
+    };
+    (
+        @split ($d:tt) [$($doc:expr,)*] [$($attr:tt)*] []
+        $assert:ident, $debug:ident, $check:ident;
+        [$($param:ident),+] [$predicate:expr]
+        [$message:expr $(, $message_arg:expr)*]
+    ) => {
+        $crate::__define_assertion! {
+            @assert ($d) [$($doc),*] [$($attr)*] []
+            $assert, $assert;
+            [$($param),+] [$predicate]
+            [$message $(, $message_arg)*]
+        }
+
+        // The debug macro checks the predicate itself instead of calling the assertion macro,
+        // so it does not depend on the path, where the assertion macro is available.
+        $crate::__define_assertion! {
+            @assert ($d)
+            [concat!(
+                "Same as `", stringify!($assert), "!`, ",
+                "but checked only if `debug_assertions` are enabled."
+            )]
+            [$($attr)*] [if cfg!(debug_assertions)]
+            $assert, $debug;
+            [$($param),+] [$predicate]
+            [$message $(, $message_arg)*]
+        }
+
+        $crate::__define_assertion! {
+            @check ($d)
+            [concat!(
+                "Same as `", stringify!($assert), "!`, ",
+                "but returns the result of the check as `bool` instead of panicking."
+            )]
+            [$($attr)*]
+            $check;
+            [$($param),+] [$predicate]
+        }
+    };
+    (
+        @assert ($d:tt) [$($doc:expr),*] [$($attr:tt)*] [$($guard:tt)*]
+        $assert:ident, $name:ident;
+        [$($param:ident),+] [$predicate:expr]
+        [$message:expr $(, $message_arg:expr)*]
+    ) => {
+        $(#[doc = $doc])*
+        $($attr)*
+        macro_rules! $name {
+            ($($d $param:expr),+) => {
+                $($guard)* {
+                    match ($(&$d $param,)+) {
+                        ($($param,)+) => {
+                            if !$predicate {
+                                $crate::__fail!([], stringify!($assert), concat!("assertion failed, ", $message) $(, $message_arg)*);
+                            }
+                        }
+                    }
+                }
+            };
+            ($($d $param:expr),+ ,) => {
+                $($guard)* {
+                    match ($(&$d $param,)+) {
+                        ($($param,)+) => {
+                            if !$predicate {
+                                $crate::__fail!([], stringify!($assert), concat!("assertion failed, ", $message) $(, $message_arg)*);
+                            }
+                        }
+                    }
+                }
+            };
+            ($($d $param:expr),+ , $d($d arg:tt)+) => {
+                $($guard)* {
+                    match ($(&$d $param,)+) {
+                        ($($param,)+) => {
+                            if !$predicate {
+                                $crate::__fail!([], stringify!($assert), concat!("assertion failed, ", $message, ": {}") $(, $message_arg)*, format_args!($d($d arg)+));
+                            }
+                        }
+                    }
+                }
+            };
+        }
+    };
+    (
+        @check ($d:tt) [$doc:expr] [$($attr:tt)*]
+        $check:ident;
+        [$($param:ident),+] [$predicate:expr]
+    ) => {
+        #[doc = $doc]
+        $($attr)*
+        macro_rules! $check {
+            ($($d $param:expr),+) => {
+                match ($(&$d $param,)+) {
+                    ($($param,)+) => $predicate,
+                }
+            };
+            ($($d $param:expr),+ ,) => {
+                match ($(&$d $param,)+) {
+                    ($($param,)+) => $predicate,
+                }
+            };
+        }
+    };
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    struct Response {
+        status: u16,
+    }
+
+    define_assertion! {
+        assert_status, debug_assert_status, check_status;
+        |response, status| response.status == *status,
+        "expected status `{}`, got `{:?}`", status, response
+    }
+
+    define_assertion! {
+        /// Asserts that the value is even.
+        #[cfg(test)]
+        assert_even, debug_assert_even, check_even;
+        |value| *value % 2 == 0,
+        "expected even value, got `{}`", value,
+    }
+
+    #[test]
+    fn passes() {
+        let response = Response { status: 200 };
+        assert_status!(response, 200);
+        assert_status!(response, 200,);
+        assert_status!(response, 200, "custom {}", "message");
+        debug_assert_status!(response, 200);
+        debug_assert_status!(response, 200,);
+        debug_assert_status!(response, 200, "custom {}", "message");
+        assert_even!(4);
+        assert_even!(4,);
+        assert_even!(4, "custom {}", "message");
+        debug_assert_even!(4);
+        debug_assert_even!(4,);
+        debug_assert_even!(4, "custom {}", "message");
+    }
+
+    #[test]
+    fn checks() {
+        let response = Response { status: 200 };
+        assert!(check_status!(response, 200));
+        assert!(!check_status!(response, 404,));
+        assert!(check_even!(2) && !check_even!(3,));
+    }
+
+    #[test]
+    fn evaluates_once() {
+        let mut calls = 0;
+        assert_even!({
+            calls += 1;
+            calls * 2
+        });
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed, expected status `200`, got `Response { status: 500 }`"
+    )]
+    fn default_panic_message() {
+        let response = Response { status: 500 };
+        assert_status!(response, 200);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected even value, got `3`: we are checking 3")]
+    fn custom_panic_message() {
+        assert_even!(3, "we are checking {}", 3);
+    }
+}
@@ -0,0 +1,347 @@
+//! Structured events of the failed assertions, emitted with `log` and `tracing` features.
+//!
+//! Fields of the events are listed in the crate documentation.
+
+use handler::Failure;
+
+/// Emits the event of the failed assertion.
+pub(crate) fn emit(failure: &Failure<'_>) {
+    #[cfg(feature = "log")]
+    log(failure);
+    #[cfg(feature = "tracing")]
+    tracing(failure);
+}
+
+#[cfg(feature = "log")]
+fn log(failure: &Failure<'_>) {
+    let location = failure.location();
+    let expressions = failure.expressions();
+    let values = failure.values();
+    ::log::error!(
+        target: "claim",
+        assertion = failure.assertion(),
+        file = location.file(),
+        line = location.line(),
+        column = location.column(),
+        left_expression = expressions.map(|(left, _)| left),
+        right_expression = expressions.map(|(_, right)| right),
+        left = values.map(|(left, _)| ::log::kv::Value::from_dyn_debug(left)),
+        right = values.map(|(_, right)| ::log::kv::Value::from_dyn_debug(right));
+        "{} failed at {}: {}",
+        failure.assertion(),
+        location,
+        failure
+    );
+}
+
+/// Names of the `tracing` event fields, in the order of their values in the `tracing` function.
+#[cfg(feature = "tracing")]
+const TRACING_FIELDS: &[&str] = &[
+    "message",
+    "assertion",
+    "file",
+    "line",
+    "column",
+    "left_expression",
+    "right_expression",
+    "left",
+    "right",
+];
+
+// The event is built from its callsite instead of the `tracing` macros,
+// as their expansion allows unused imports, which are forbidden in this crate.
+#[cfg(feature = "tracing")]
+static TRACING_CALLSITE: ::tracing::callsite::DefaultCallsite =
+    ::tracing::callsite::DefaultCallsite::new(&TRACING_METADATA);
+
+#[cfg(feature = "tracing")]
+static TRACING_METADATA: ::tracing::Metadata<'static> = ::tracing::Metadata::new(
+    concat!("event ", file!(), ":", line!()),
+    "claim",
+    ::tracing::Level::ERROR,
+    Some(file!()),
+    Some(line!()),
+    Some(module_path!()),
+    ::tracing::field::FieldSet::new(
+        TRACING_FIELDS,
+        ::tracing::callsite::Identifier(&TRACING_CALLSITE),
+    ),
+    ::tracing::metadata::Kind::EVENT,
+);
+
+#[cfg(feature = "tracing")]
+fn tracing(failure: &Failure<'_>) {
+    use tracing::field::{debug, Value};
+
+    if TRACING_CALLSITE.interest().is_never() {
+        return;
+    }
+    let metadata = &TRACING_METADATA;
+    if !::tracing::dispatcher::get_default(|dispatch| dispatch.enabled(metadata)) {
+        return;
+    }
+    let location = failure.location();
+    let expressions = failure.expressions();
+    let values = failure.values();
+    let message = format_args!(
+        "{} failed at {}: {}",
+        failure.assertion(),
+        location,
+        failure
+    );
+    let assertion = failure.assertion();
+    let file = location.file();
+    let line = location.line();
+    let column = location.column();
+    let left_expression = expressions.map(|(left, _)| left);
+    let right_expression = expressions.map(|(_, right)| right);
+    let left = values.map(|(left, _)| debug(left));
+    let right = values.map(|(_, right)| debug(right));
+    let fields = metadata.fields();
+    let mut names = fields.iter();
+    let mut field = || names.next().expect("missing field of the tracing event");
+    let keys = [
+        field(),
+        field(),
+        field(),
+        field(),
+        field(),
+        field(),
+        field(),
+        field(),
+        field(),
+    ];
+    let values: [(_, Option<&dyn Value>); 9] = [
+        (&keys[0], Some(&message)),
+        (&keys[1], Some(&assertion)),
+        (&keys[2], Some(&file)),
+        (&keys[3], Some(&line)),
+        (&keys[4], Some(&column)),
+        (&keys[5], Some(&left_expression)),
+        (&keys[6], Some(&right_expression)),
+        (&keys[7], Some(&left)),
+        (&keys[8], Some(&right)),
+    ];
+    ::tracing::Event::dispatch(metadata, &fields.value_set(&values));
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use std::format;
+    use std::panic::{catch_unwind, Location};
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+
+    type Fields = Vec<(String, String)>;
+
+    fn field<'a>(fields: &'a Fields, name: &str) -> Option<&'a str> {
+        fields
+            .iter()
+            .find(|field| field.0 == name)
+            .map(|field| field.1.as_str())
+    }
+
+    #[cfg(feature = "log")]
+    mod log {
+        use std::cell::RefCell;
+        use std::string::{String, ToString};
+        use std::vec::Vec;
+
+        use log::kv::{Error, Key, Value, VisitSource};
+        use log::{Log, Metadata, Record};
+
+        use super::Fields;
+
+        std::thread_local! {
+            static RECORDS: RefCell<Vec<(String, Fields)>> = const { RefCell::new(Vec::new()) };
+        }
+
+        struct Recorder;
+
+        impl Log for Recorder {
+            fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+                metadata.target() == "claim"
+            }
+
+            fn log(&self, record: &Record<'_>) {
+                if !self.enabled(record.metadata()) {
+                    return;
+                }
+                let mut fields = FieldsVisitor(Vec::new());
+                record.key_values().visit(&mut fields).unwrap();
+                let message = record.args().to_string();
+                RECORDS.with(|records| records.borrow_mut().push((message, fields.0)));
+            }
+
+            fn flush(&self) {}
+        }
+
+        struct FieldsVisitor(Fields);
+
+        impl<'kvs> VisitSource<'kvs> for FieldsVisitor {
+            fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), Error> {
+                // Missing fields are recorded as null values, which are skipped like in `tracing`.
+                let value = value.to_string();
+                if value != Value::null().to_string() {
+                    self.0.push((key.to_string(), value));
+                }
+                Ok(())
+            }
+        }
+
+        static RECORDER: Recorder = Recorder;
+
+        // Logger is global, so it only records events of the current thread.
+        pub fn recorded<F: FnOnce()>(f: F) -> Vec<(String, Fields)> {
+            if log::set_logger(&RECORDER).is_ok() {
+                log::set_max_level(log::LevelFilter::Error);
+            }
+            RECORDS.with(|records| records.borrow_mut().clear());
+            f();
+            RECORDS.with(|records| records.borrow_mut().split_off(0))
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    mod tracing {
+        use std::fmt;
+        use std::string::{String, ToString};
+        use std::sync::{Arc, Mutex};
+        use std::vec::Vec;
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        use super::Fields;
+
+        #[derive(Clone, Default)]
+        struct Recorder(Arc<Mutex<Vec<(String, Fields)>>>);
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+                metadata.target() == "claim"
+            }
+
+            fn new_span(&self, _span: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut fields = FieldsVisitor(Vec::new());
+                event.record(&mut fields);
+                let message = fields
+                    .0
+                    .iter()
+                    .position(|field| field.0 == "message")
+                    .map(|index| fields.0.remove(index).1)
+                    .unwrap_or_default();
+                self.0.lock().unwrap().push((message, fields.0));
+            }
+
+            fn enter(&self, _span: &Id) {}
+
+            fn exit(&self, _span: &Id) {}
+        }
+
+        struct FieldsVisitor(Fields);
+
+        impl Visit for FieldsVisitor {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.push((field.name().to_string(), value.to_string()));
+            }
+
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                self.0
+                    .push((field.name().to_string(), std::format!("{:?}", value)));
+            }
+        }
+
+        pub fn recorded<F: FnOnce()>(f: F) -> Vec<(String, Fields)> {
+            let recorder = Recorder::default();
+            tracing::subscriber::with_default(recorder.clone(), f);
+            let records = recorder.0.lock().unwrap().split_off(0);
+            records
+        }
+    }
+
+    // Both features emit the same fields, so the tests are shared.
+    #[allow(clippy::vec_init_then_push)]
+    fn recorded<F: Fn()>(f: F) -> Vec<Vec<(String, Fields)>> {
+        let mut recorded = Vec::new();
+        #[cfg(feature = "log")]
+        recorded.push(log::recorded(&f));
+        #[cfg(feature = "tracing")]
+        recorded.push(tracing::recorded(&f));
+        recorded
+    }
+
+    #[test]
+    fn emits_comparisons() {
+        let line = Location::caller().line() + 3;
+        for records in recorded(|| {
+            let left = 1;
+            assert!(catch_unwind(|| crate::assert_ge!(left, 1 + 1, "custom")).is_err());
+        }) {
+            assert_eq!(records.len(), 1);
+            let (message, fields) = &records[0];
+            assert!(message.starts_with(&format!("assert_ge failed at src/events.rs:{}:", line)));
+            assert!(message.ends_with(
+                ": assertion failed: `(left >= right)`\n    left: `1`,\n    right: `2`: custom"
+            ));
+            assert_eq!(field(fields, "assertion"), Some("assert_ge"));
+            assert_eq!(field(fields, "file"), Some("src/events.rs"));
+            assert_eq!(field(fields, "line"), Some(line.to_string().as_str()));
+            assert_eq!(field(fields, "left_expression"), Some("left"));
+            assert_eq!(field(fields, "right_expression"), Some("1 + 1"));
+            assert_eq!(field(fields, "left"), Some("1"));
+            assert_eq!(field(fields, "right"), Some("2"));
+        }
+    }
+
+    #[test]
+    fn emits_equality_values() {
+        for records in recorded(|| {
+            assert!(catch_unwind(|| crate::assert_some_eq!(Some("a"), "b")).is_err());
+        }) {
+            assert_eq!(records.len(), 1);
+            let fields = &records[0].1;
+            assert_eq!(field(fields, "assertion"), Some("assert_some_eq"));
+            assert_eq!(field(fields, "left"), Some(r#""a""#));
+            assert_eq!(field(fields, "right"), Some(r#""b""#));
+            assert_eq!(field(fields, "left_expression"), None);
+        }
+    }
+
+    #[test]
+    fn emits_other_failures() {
+        for records in recorded(|| {
+            assert!(catch_unwind(|| crate::assert_none!(Some(1))).is_err());
+        }) {
+            assert_eq!(records.len(), 1);
+            let fields = &records[0].1;
+            assert_eq!(field(fields, "assertion"), Some("assert_none"));
+            assert_eq!(field(fields, "left"), None);
+            assert_eq!(field(fields, "right"), None);
+        }
+    }
+
+    #[test]
+    fn emits_violations() {
+        for records in recorded(|| {
+            let (checked, violations) = crate::invariants::tests::recorded(|| {
+                crate::invariants::check(|| crate::assert_some!(None::<i32>))
+            });
+            assert!(checked.is_err());
+            assert_eq!(violations.len(), 1);
+        }) {
+            assert_eq!(records.len(), 1);
+            assert_eq!(field(&records[0].1, "assertion"), Some("assert_some"));
+        }
+    }
+}
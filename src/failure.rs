@@ -0,0 +1,169 @@
+// Failures of all assertions are reported with the macros below,
+// so they are passed to the failure handler, when it is available.
+//
+// The first argument is `[]` for the assertions, which panic on failure,
+// or `['label]` for the `expect_*` macros, which break out of the labeled block with the violation.
+
+/// Reports the failed assertion with the given message.
+#[cfg(rustc_1_63)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fail {
+    ([], $assertion:expr, $($arg:tt)+) => {
+        $crate::__private::fail($assertion, format_args!($($arg)+))
+    };
+    ([$label:lifetime], $assertion:expr, $($arg:tt)+) => {
+        break $label $crate::__private::violate($assertion, format_args!($($arg)+))
+    };
+}
+
+/// Reports the failed assertion with the given message.
+#[cfg(not(rustc_1_63))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fail {
+    ($fail:tt, $assertion:expr, $($arg:tt)+) => {
+        panic!($($arg)+)
+    };
+}
+
+/// Reports the failed comparison of two expressions with the given message.
+#[cfg(rustc_1_63)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fail_cmp {
+    ([], $assertion:expr, ($left:expr, $right:expr), $left_val:expr, $right_val:expr, $($arg:tt)+) => {
+        $crate::__private::fail_cmp(
+            $assertion,
+            Some((stringify!($left), stringify!($right))),
+            $left_val,
+            $right_val,
+            format_args!($($arg)+),
+        )
+    };
+    ([$label:lifetime], $assertion:expr, ($left:expr, $right:expr), $left_val:expr, $right_val:expr, $($arg:tt)+) => {
+        break $label $crate::__private::violate_cmp(
+            $assertion,
+            Some((stringify!($left), stringify!($right))),
+            $left_val,
+            $right_val,
+            format_args!($($arg)+),
+        )
+    };
+}
+
+/// Reports the failed comparison of two expressions with the given message.
+#[cfg(not(rustc_1_63))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fail_cmp {
+    ($fail:tt, $assertion:expr, ($left:expr, $right:expr), $left_val:expr, $right_val:expr, $($arg:tt)+) => {
+        panic!($($arg)+)
+    };
+}
+
+/// Same as `assert_eq!`, but reports the failed assertion with the given name.
+#[cfg(rustc_1_63)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_eq {
+    ([], $assertion:expr, $left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    $crate::__private::fail_eq($assertion, left_val, right_val, None);
+                }
+            }
+        }
+    };
+    ([], $assertion:expr, $left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    $crate::__private::fail_eq($assertion, left_val, right_val, Some(format_args!($($arg)+)));
+                }
+            }
+        }
+    };
+    ([$label:lifetime], $assertion:expr, $left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    break $label $crate::__private::violate_eq($assertion, left_val, right_val, None);
+                }
+            }
+        }
+    };
+    ([$label:lifetime], $assertion:expr, $left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    break $label $crate::__private::violate_eq($assertion, left_val, right_val, Some(format_args!($($arg)+)));
+                }
+            }
+        }
+    };
+}
+
+/// Same as `assert_eq!`, but reports the failed assertion with the given name.
+#[cfg(not(rustc_1_63))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_eq {
+    ($fail:tt, $assertion:expr, $($arg:tt)+) => {
+        assert_eq!($($arg)+)
+    };
+}
+
+/// Same as `assert_ne!`, but reports the failed assertion with the given name.
+#[cfg(rustc_1_63)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_ne {
+    ([], $assertion:expr, $left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val != *right_val) {
+                    $crate::__private::fail_ne($assertion, left_val, right_val, None);
+                }
+            }
+        }
+    };
+    ([], $assertion:expr, $left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val != *right_val) {
+                    $crate::__private::fail_ne($assertion, left_val, right_val, Some(format_args!($($arg)+)));
+                }
+            }
+        }
+    };
+    ([$label:lifetime], $assertion:expr, $left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val != *right_val) {
+                    break $label $crate::__private::violate_ne($assertion, left_val, right_val, None);
+                }
+            }
+        }
+    };
+    ([$label:lifetime], $assertion:expr, $left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val != *right_val) {
+                    break $label $crate::__private::violate_ne($assertion, left_val, right_val, Some(format_args!($($arg)+)));
+                }
+            }
+        }
+    };
+}
+
+/// Same as `assert_ne!`, but reports the failed assertion with the given name.
+#[cfg(not(rustc_1_63))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_ne {
+    ($fail:tt, $assertion:expr, $($arg:tt)+) => {
+        assert_ne!($($arg)+)
+    };
+}
@@ -0,0 +1,524 @@
+//! Method-style assertions with the [`Claim`] trait.
+//!
+//! This module is available for Rust 1.46+.
+//!
+//! [`Claim`] is implemented for all types, and its methods mirror the macros of this crate,
+//! ex. [`claim_ok`] is the same check as the [`assert_ok!`] macro, with the same panic message.
+//! Methods return the checked value, or the value inside of it, so the checks can be chained.
+//! All methods are `#[track_caller]`, so panics point to the caller location.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use claim::fluent::Claim;
+//!
+//! let res: Result<i32, ()> = Ok(5);
+//! res.claim_ok().claim_eq(&5).claim_gt(&3);
+//!
+//! let opt = Some(vec![1, 2, 3]);
+//! opt.claim_some().claim_prefix(&[1, 2]).claim_all(|x| *x > 0);
+//! ```
+//!
+//! ```rust,should_panic
+//! use claim::fluent::Claim;
+//!
+//! let res: Result<i32, &str> = Err("oops");
+//! res.claim_ok();  // Will panic
+//! ```
+//!
+//! [`Claim`]: ./trait.Claim.html
+//! [`claim_ok`]: ./trait.Claim.html#method.claim_ok
+//! [`assert_ok!`]: ../macro.assert_ok.html
+
+use core::fmt::Debug;
+use core::task::Poll;
+
+use __private::{all, any, prefix, suffix, Values};
+
+/// Types, which can be checked with the `Result` methods of the [`Claim`] trait.
+///
+/// [`Claim`]: ./trait.Claim.html
+pub trait ResultLike {
+    /// Type of the `Ok` value.
+    type Ok;
+    /// Type of the `Err` value.
+    type Err;
+
+    /// Converts the value into `Result`.
+    fn into_result(self) -> Result<Self::Ok, Self::Err>;
+}
+
+impl<T, E> ResultLike for Result<T, E> {
+    type Ok = T;
+    type Err = E;
+
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+}
+
+/// Types, which can be checked with the `Option` methods of the [`Claim`] trait.
+///
+/// [`Claim`]: ./trait.Claim.html
+pub trait OptionLike {
+    /// Type of the `Some` value.
+    type Value;
+
+    /// Converts the value into `Option`.
+    fn into_option(self) -> Option<Self::Value>;
+}
+
+impl<T> OptionLike for Option<T> {
+    type Value = T;
+
+    fn into_option(self) -> Option<T> {
+        self
+    }
+}
+
+/// Types, which can be checked with the `Poll` methods of the [`Claim`] trait.
+///
+/// [`Claim`]: ./trait.Claim.html
+pub trait PollLike {
+    /// Type of the `Ready` value.
+    type Value;
+
+    /// Converts the value into `Poll`.
+    fn into_poll(self) -> Poll<Self::Value>;
+}
+
+impl<T> PollLike for Poll<T> {
+    type Value = T;
+
+    fn into_poll(self) -> Poll<T> {
+        self
+    }
+}
+
+/// Method-style assertions, which are available for all types.
+///
+/// Every method is available only if the value supports the corresponding check,
+/// ex. [`claim_ok`] is available for `Result` values and [`claim_gt`] for the `PartialOrd` values.
+///
+/// [`claim_ok`]: #method.claim_ok
+/// [`claim_gt`]: #method.claim_gt
+pub trait Claim: Sized {
+    /// Asserts that the value is equal to the `expected` one and returns it,
+    /// same to the [`assert_eq!`] macro.
+    ///
+    /// [`assert_eq!`]: https://doc.rust-lang.org/std/macro.assert_eq.html
+    #[track_caller]
+    fn claim_eq<U: ?Sized + Debug>(self, expected: &U) -> Self
+    where
+        Self: PartialEq<U> + Debug,
+    {
+        __assert_eq!([], "claim_eq", self, *expected);
+        self
+    }
+
+    /// Asserts that the value is not equal to the `expected` one and returns it,
+    /// same to the [`assert_ne!`] macro.
+    ///
+    /// [`assert_ne!`]: https://doc.rust-lang.org/std/macro.assert_ne.html
+    #[track_caller]
+    fn claim_ne<U: ?Sized + Debug>(self, expected: &U) -> Self
+    where
+        Self: PartialEq<U> + Debug,
+    {
+        __assert_ne!([], "claim_ne", self, *expected);
+        self
+    }
+
+    /// Asserts that the value is greater than the `expected` one and returns it,
+    /// same to the [`assert_gt!`] macro.
+    ///
+    /// [`assert_gt!`]: ../macro.assert_gt.html
+    #[track_caller]
+    fn claim_gt<U: ?Sized + Debug>(self, expected: &U) -> Self
+    where
+        Self: PartialOrd<U> + Debug,
+    {
+        if !(self > *expected) {
+            fail_cmp("claim_gt", ">", &self, expected);
+        }
+        self
+    }
+
+    /// Asserts that the value is greater than or equal to the `expected` one and returns it,
+    /// same to the [`assert_ge!`] macro.
+    ///
+    /// [`assert_ge!`]: ../macro.assert_ge.html
+    #[track_caller]
+    fn claim_ge<U: ?Sized + Debug>(self, expected: &U) -> Self
+    where
+        Self: PartialOrd<U> + Debug,
+    {
+        if !(self >= *expected) {
+            fail_cmp("claim_ge", ">=", &self, expected);
+        }
+        self
+    }
+
+    /// Asserts that the value is less than the `expected` one and returns it,
+    /// same to the [`assert_lt!`] macro.
+    ///
+    /// [`assert_lt!`]: ../macro.assert_lt.html
+    #[track_caller]
+    fn claim_lt<U: ?Sized + Debug>(self, expected: &U) -> Self
+    where
+        Self: PartialOrd<U> + Debug,
+    {
+        if !(self < *expected) {
+            fail_cmp("claim_lt", "<", &self, expected);
+        }
+        self
+    }
+
+    /// Asserts that the value is less than or equal to the `expected` one and returns it,
+    /// same to the [`assert_le!`] macro.
+    ///
+    /// [`assert_le!`]: ../macro.assert_le.html
+    #[track_caller]
+    fn claim_le<U: ?Sized + Debug>(self, expected: &U) -> Self
+    where
+        Self: PartialOrd<U> + Debug,
+    {
+        if !(self <= *expected) {
+            fail_cmp("claim_le", "<=", &self, expected);
+        }
+        self
+    }
+
+    /// Asserts that the value is `Ok(..)` and returns the value inside of it,
+    /// same to the [`assert_ok!`] macro.
+    ///
+    /// [`assert_ok!`]: ../macro.assert_ok.html
+    #[track_caller]
+    fn claim_ok(self) -> <Self as ResultLike>::Ok
+    where
+        Self: ResultLike,
+        <Self as ResultLike>::Err: Debug,
+    {
+        match self.into_result() {
+            Ok(t) => t,
+            Err(e) => __fail!(
+                [],
+                "claim_ok",
+                "assertion failed, expected Ok(..), got Err({:?})",
+                e
+            ),
+        }
+    }
+
+    /// Asserts that the value is `Err(..)` and returns the value inside of it,
+    /// same to the [`assert_err!`] macro.
+    ///
+    /// [`assert_err!`]: ../macro.assert_err.html
+    #[track_caller]
+    fn claim_err(self) -> <Self as ResultLike>::Err
+    where
+        Self: ResultLike,
+        <Self as ResultLike>::Ok: Debug,
+    {
+        match self.into_result() {
+            Ok(t) => __fail!(
+                [],
+                "claim_err",
+                "assertion failed, expected Err(..), got Ok({:?})",
+                t
+            ),
+            Err(e) => e,
+        }
+    }
+
+    /// Asserts that the value is `Some(..)` and returns the value inside of it,
+    /// same to the [`assert_some!`] macro.
+    ///
+    /// [`assert_some!`]: ../macro.assert_some.html
+    #[track_caller]
+    fn claim_some(self) -> <Self as OptionLike>::Value
+    where
+        Self: OptionLike,
+    {
+        match self.into_option() {
+            Some(t) => t,
+            None => __fail!(
+                [],
+                "claim_some",
+                "assertion failed, expected Some(..), got None"
+            ),
+        }
+    }
+
+    /// Asserts that the value is `None`, same to the [`assert_none!`] macro.
+    ///
+    /// [`assert_none!`]: ../macro.assert_none.html
+    #[track_caller]
+    fn claim_none(self)
+    where
+        Self: OptionLike,
+        <Self as OptionLike>::Value: Debug,
+    {
+        let value = self.into_option();
+        if value.is_some() {
+            __fail!(
+                [],
+                "claim_none",
+                "assertion failed, expected None, got {:?}",
+                value
+            );
+        }
+    }
+
+    /// Asserts that the value is `Ready(..)` and returns the value inside of it,
+    /// same to the [`assert_ready!`] macro.
+    ///
+    /// [`assert_ready!`]: ../macro.assert_ready.html
+    #[track_caller]
+    fn claim_ready(self) -> <Self as PollLike>::Value
+    where
+        Self: PollLike,
+    {
+        match self.into_poll() {
+            Poll::Ready(t) => t,
+            Poll::Pending => __fail!(
+                [],
+                "claim_ready",
+                "assertion failed, expected Ready(..), got Pending"
+            ),
+        }
+    }
+
+    /// Asserts that the value is `Pending`, same to the [`assert_pending!`] macro.
+    ///
+    /// [`assert_pending!`]: ../macro.assert_pending.html
+    #[track_caller]
+    fn claim_pending(self)
+    where
+        Self: PollLike,
+        <Self as PollLike>::Value: Debug,
+    {
+        let value = self.into_poll();
+        if value.is_ready() {
+            __fail!(
+                [],
+                "claim_pending",
+                "assertion failed, expected Pending, got {:?}",
+                value
+            );
+        }
+    }
+
+    /// Asserts that all elements satisfy the `predicate` and returns the value,
+    /// same to the [`assert_all!`] macro.
+    ///
+    /// [`assert_all!`]: ../macro.assert_all.html
+    #[track_caller]
+    fn claim_all<T, F>(self, predicate: F) -> Self
+    where
+        Self: AsRef<[T]>,
+        T: Debug,
+        F: Fn(&T) -> bool,
+    {
+        {
+            let result = all(self.as_ref().iter(), predicate);
+            if !result.is_ok() {
+                __fail!(
+                    [],
+                    "claim_all",
+                    r#"assertion failed: `(all elements satisfy predicate)`{}"#,
+                    result
+                );
+            }
+        }
+        self
+    }
+
+    /// Asserts that at least one element satisfies the `predicate` and returns the value,
+    /// same to the [`assert_any!`] macro.
+    ///
+    /// [`assert_any!`]: ../macro.assert_any.html
+    #[track_caller]
+    fn claim_any<T, F>(self, predicate: F) -> Self
+    where
+        Self: AsRef<[T]>,
+        T: Debug,
+        F: Fn(&T) -> bool,
+    {
+        {
+            let result = any(self.as_ref().iter(), predicate);
+            if !result.is_ok() {
+                __fail!(
+                    [],
+                    "claim_any",
+                    r#"assertion failed: `(any element satisfies predicate)`{}"#,
+                    result
+                );
+            }
+        }
+        self
+    }
+
+    /// Asserts that the elements start with the `expected` ones and returns the value,
+    /// same to the [`assert_prefix!`] macro.
+    ///
+    /// [`assert_prefix!`]: ../macro.assert_prefix.html
+    #[track_caller]
+    fn claim_prefix<T, U>(self, expected: &[U]) -> Self
+    where
+        Self: AsRef<[T]>,
+        T: PartialEq<U> + Debug,
+        U: Debug,
+    {
+        {
+            let expected = Values(expected.iter());
+            let result = prefix(self.as_ref().iter(), &expected);
+            if !result.is_ok() {
+                __fail!(
+                    [],
+                    "claim_prefix",
+                    r#"assertion failed: `(left starts with right)`{}"#,
+                    result
+                );
+            }
+        }
+        self
+    }
+
+    /// Asserts that the elements end with the `expected` ones and returns the value,
+    /// same to the [`assert_suffix!`] macro.
+    ///
+    /// [`assert_suffix!`]: ../macro.assert_suffix.html
+    #[track_caller]
+    fn claim_suffix<T, U>(self, expected: &[U]) -> Self
+    where
+        Self: AsRef<[T]>,
+        T: PartialEq<U> + Debug,
+        U: Debug,
+    {
+        {
+            let expected = Values(expected.iter());
+            let result = suffix(self.as_ref().iter(), &expected);
+            if !result.is_ok() {
+                __fail!(
+                    [],
+                    "claim_suffix",
+                    r#"assertion failed: `(left ends with right)`{}"#,
+                    result
+                );
+            }
+        }
+        self
+    }
+}
+
+impl<T> Claim for T {}
+
+/// Reports the failed ordering comparison of the value with the `expected` one.
+#[cfg(rustc_1_63)]
+#[track_caller]
+fn fail_cmp<T, U>(assertion: &'static str, op: &str, value: &T, expected: &U) -> !
+where
+    T: ?Sized + Debug,
+    U: ?Sized + Debug,
+{
+    ::__private::fail_cmp(
+        assertion,
+        None,
+        &value,
+        &expected,
+        format_args!(
+            "assertion failed: `(left {} right)`\n    left: `{:?}`,\n    right: `{:?}`",
+            op, value, expected
+        ),
+    )
+}
+
+/// Reports the failed ordering comparison of the value with the `expected` one.
+#[cfg(not(rustc_1_63))]
+#[track_caller]
+fn fail_cmp<T, U>(_assertion: &'static str, op: &str, value: &T, expected: &U) -> !
+where
+    T: ?Sized + Debug,
+    U: ?Sized + Debug,
+{
+    panic!(
+        "assertion failed: `(left {} right)`\n    left: `{:?}`,\n    right: `{:?}`",
+        op, value, expected
+    )
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::task::Poll;
+    use core::time::Duration;
+
+    use super::Claim;
+
+    #[test]
+    fn chains() {
+        let res: Result<i32, ()> = Ok(5);
+        let _ = res
+            .claim_ok()
+            .claim_eq(&5)
+            .claim_ne(&4)
+            .claim_gt(&3)
+            .claim_ge(&5);
+        let _ = Some(2).claim_some().claim_lt(&3).claim_le(&2);
+        let _ = Err::<(), _>(1).claim_err().claim_eq(&1);
+        None::<i32>.claim_none();
+        let _ = Poll::Ready(1).claim_ready().claim_eq(&1);
+        Poll::<i32>::Pending.claim_pending();
+    }
+
+    #[test]
+    fn foreign_types() {
+        let timeout = Duration::from_secs(1);
+        let _ = Some(Duration::from_secs(2)).claim_some().claim_gt(&timeout);
+        let _ = Some((1, 2))
+            .claim_some()
+            .claim_eq(&(1, 2))
+            .claim_lt(&(2, 0));
+    }
+
+    #[test]
+    fn slices() {
+        let items = [1, 2, 3];
+        let _ = items
+            .claim_all(|x| *x > 0)
+            .claim_any(|x| *x == 2)
+            .claim_prefix(&[1, 2])
+            .claim_suffix(&[2, 3]);
+        let _ = (&items[..]).claim_prefix(&[1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ok(..), got Err(())")]
+    fn ok_panic_message() {
+        let res: Result<i32, ()> = Err(());
+        let _ = res.claim_ok();
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected None, got Some(1)")]
+    fn none_panic_message() {
+        Some(1).claim_none();
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left > right)`
+    left: `1`,
+    right: `3`"#)]
+    fn gt_panic_message() {
+        let _ = Some(1).claim_some().claim_gt(&3);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(all elements satisfy predicate)`
+    failed elements: 1 of 3,
+    [1]: `-2`"#)]
+    fn all_panic_message() {
+        let _ = [1, -2, 3].claim_all(|x| *x > 0);
+    }
+}
@@ -0,0 +1,321 @@
+//! Handler of the assertion failures.
+//!
+//! This module is available for Rust 1.63+, including `no_std` builds.
+//!
+//! By default, failed assertions panic. A [`Handler`] set with [`set_handler`] is called
+//! with the [`Failure`] record before that, so the failures can be logged, reported
+//! or handled without unwinding, ex. by aborting the process.
+//! If the handler returns, the assertion panics with the usual message.
+//!
+//! The handler is stored in a global atomic, so it can be set in `no_std` builds too,
+//! ex. to report failures on the targets, where unwinding is not available,
+//! before the `#[panic_handler]` of the binary is called.
+//!
+//! ## Examples
+//!
+//! ```rust,should_panic
+//! # #[macro_use] extern crate claim;
+//! use claim::handler::{set_handler, Failure};
+//!
+//! fn report(failure: &Failure<'_>) {
+//!     eprintln!("{} failed at {}: {}", failure.assertion(), failure.location(), failure);
+//! }
+//!
+//! # fn main() {
+//! let _ = set_handler(report);
+//! assert_some!(None::<i32>);  // Will be reported and then panic
+//! # }
+//! ```
+//!
+//! [`Handler`]: ./type.Handler.html
+//! [`set_handler`]: ./fn.set_handler.html
+//! [`Failure`]: ./struct.Failure.html
+
+use core::fmt;
+use core::mem;
+use core::panic::Location;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Function, which is called with the failed assertion record.
+pub type Handler = fn(&Failure<'_>);
+
+/// Address of the current handler, or zero, if it is not set.
+static HANDLER: AtomicUsize = AtomicUsize::new(0);
+
+/// Converts the address stored in `HANDLER` back to the handler.
+#[allow(unsafe_code)]
+fn from_address(address: usize) -> Option<Handler> {
+    if address == 0 {
+        None
+    } else {
+        // SAFETY: non-zero addresses are stored only by `set_handler` from the `Handler` values,
+        // and function pointers are never null.
+        Some(unsafe { mem::transmute::<usize, Handler>(address) })
+    }
+}
+
+/// Record of the failed assertion.
+///
+/// Its `Display` implementation writes the same message, which the assertion panics with.
+#[derive(Debug)]
+pub struct Failure<'a> {
+    assertion: &'static str,
+    message: fmt::Arguments<'a>,
+    location: &'static Location<'static>,
+    expressions: Option<(&'static str, &'static str)>,
+    values: Option<(&'a dyn fmt::Debug, &'a dyn fmt::Debug)>,
+}
+
+impl<'a> Failure<'a> {
+    /// Creates a record for the failed assertion at the caller location.
+    #[track_caller]
+    pub(crate) fn new(assertion: &'static str, message: fmt::Arguments<'a>) -> Self {
+        Failure {
+            assertion,
+            message,
+            location: Location::caller(),
+            expressions: None,
+            values: None,
+        }
+    }
+
+    /// Adds the source code of the compared expressions to the record.
+    pub(crate) fn with_expressions(mut self, left: &'static str, right: &'static str) -> Self {
+        self.expressions = Some((left, right));
+        self
+    }
+
+    /// Adds the compared values to the record.
+    pub(crate) fn with_values(
+        mut self,
+        left: &'a dyn fmt::Debug,
+        right: &'a dyn fmt::Debug,
+    ) -> Self {
+        self.values = Some((left, right));
+        self
+    }
+
+    /// Returns name of the failed assertion, ex. `assert_ok`.
+    pub fn assertion(&self) -> &'static str {
+        self.assertion
+    }
+
+    /// Returns the failure message, including the custom message, if any.
+    pub fn message(&self) -> &fmt::Arguments<'a> {
+        &self.message
+    }
+
+    /// Returns location of the failed assertion.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Returns the source code of the left and right expressions,
+    /// if the failed assertion compares two expressions, ex. `assert_ge`.
+    pub fn expressions(&self) -> Option<(&'static str, &'static str)> {
+        self.expressions
+    }
+
+    /// Returns the left and right values, if the failed assertion compares two values,
+    /// ex. `assert_ge` or `assert_ok_eq`.
+    pub fn values(&self) -> Option<(&'a dyn fmt::Debug, &'a dyn fmt::Debug)> {
+        self.values
+    }
+}
+
+impl<'a> fmt::Display for Failure<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.message, f)
+    }
+}
+
+/// Sets the handler of the assertion failures and returns the previous one.
+pub fn set_handler(handler: Handler) -> Option<Handler> {
+    from_address(HANDLER.swap(handler as usize, Ordering::AcqRel))
+}
+
+/// Removes the handler of the assertion failures and returns it,
+/// so the failed assertions only panic.
+pub fn take_handler() -> Option<Handler> {
+    from_address(HANDLER.swap(0, Ordering::AcqRel))
+}
+
+/// Calls the handler, if it is set.
+pub(crate) fn handle(failure: &Failure<'_>) {
+    if let Some(handler) = from_address(HANDLER.load(Ordering::Acquire)) {
+        handler(failure);
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use std::cell::RefCell;
+    use std::panic::{catch_unwind, AssertUnwindSafe, Location};
+    use std::string::{String, ToString};
+    use std::sync::Mutex;
+    use std::vec::Vec;
+
+    use crate::fluent::Claim;
+
+    use super::{set_handler, take_handler, Failure, Handler};
+
+    type Compared = (Option<(&'static str, &'static str)>, Option<String>);
+
+    std::thread_local! {
+        static FAILURES: RefCell<Vec<(&'static str, String, u32)>> = const { RefCell::new(Vec::new()) };
+        static COMPARED: RefCell<Vec<Compared>> = const { RefCell::new(Vec::new()) };
+    }
+
+    // Handler is global, so it only records failures of the current thread
+    // and lets the other tests panic as usual.
+    fn record(failure: &Failure<'_>) {
+        FAILURES.with(|failures| {
+            failures.borrow_mut().push((
+                failure.assertion(),
+                failure.to_string(),
+                failure.location().line(),
+            ))
+        });
+        let values = failure
+            .values()
+            .map(|(left, right)| std::format!("{:?} {:?}", left, right));
+        COMPARED.with(|compared| compared.borrow_mut().push((failure.expressions(), values)));
+    }
+
+    // Number of the running tests, which record failures, and the handler to restore after them.
+    static RECORDING: Mutex<(usize, Option<Handler>)> = Mutex::new((0, None));
+
+    /// Sets the recording handler and restores the previous one, when the last test is finished.
+    struct Recording;
+
+    impl Recording {
+        fn start() -> Self {
+            let mut recording = RECORDING.lock().unwrap_or_else(|error| error.into_inner());
+            if recording.0 == 0 {
+                recording.1 = set_handler(record);
+            }
+            recording.0 += 1;
+            Recording
+        }
+    }
+
+    impl Drop for Recording {
+        fn drop(&mut self) {
+            let mut recording = RECORDING.lock().unwrap_or_else(|error| error.into_inner());
+            recording.0 -= 1;
+            if recording.0 == 0 {
+                let _ = match recording.1.take() {
+                    Some(previous) => set_handler(previous),
+                    None => take_handler(),
+                };
+            }
+        }
+    }
+
+    fn recorded<F: FnOnce()>(f: F) -> Vec<(&'static str, String, u32)> {
+        let _recording = Recording::start();
+        FAILURES.with(|failures| failures.borrow_mut().clear());
+        assert!(catch_unwind(AssertUnwindSafe(f)).is_err());
+        FAILURES.with(|failures| failures.borrow_mut().split_off(0))
+    }
+
+    #[test]
+    fn handles_failures() {
+        let mut line = 0;
+        let failures = recorded(|| {
+            line = Location::caller().line() + 1;
+            let _ = crate::assert_some!(None::<i32>, "custom {}", "message");
+        });
+        assert_eq!(
+            failures,
+            [(
+                "assert_some",
+                "assertion failed, expected Some(..), got None: custom message".to_string(),
+                line
+            )]
+        );
+    }
+
+    #[test]
+    fn handles_equality_comparisons() {
+        let failures = recorded(|| {
+            let _ = crate::assert_ok_eq!(Ok::<i32, ()>(1), 2);
+        });
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, "assert_ok_eq");
+        assert_eq!(
+            failures[0].1,
+            "assertion failed: `(left == right)`\n    left: `1`,\n    right: `2`"
+        );
+
+        let payload = catch_unwind(|| crate::assert_some_ne!(Some(1), 1, "custom")).unwrap_err();
+        assert_eq!(
+            payload.downcast_ref::<String>().map(|s| s.as_str()),
+            Some("assertion failed: `(left != right)`\n    left: `1`,\n    right: `1`: custom")
+        );
+    }
+
+    #[test]
+    fn handles_assertion_names() {
+        let names = [
+            recorded(|| {
+                crate::assert_prefix!([1, 2, 3], [2]);
+            }),
+            recorded(|| {
+                crate::assert_suffix!([1, 2, 3], [2]);
+            }),
+            recorded(|| {
+                crate::assert_subsequence!([1, 2, 3], [3, 1]);
+            }),
+        ]
+        .iter()
+        .map(|failures| failures[0].0)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["assert_prefix", "assert_suffix", "assert_subsequence"]
+        );
+    }
+
+    #[test]
+    fn handles_compared_values() {
+        let left = 1;
+        let _ = recorded(|| crate::assert_gt!(left, 2));
+        let _ = recorded(|| crate::assert_cmp!(left < 2 <= left - 1));
+        let _ = recorded(|| {
+            let _ = crate::assert_none!(Some(1));
+        });
+        let _ = recorded(|| {
+            let _ = left.claim_gt(&2);
+        });
+        let compared = COMPARED.with(|compared| compared.borrow_mut().split_off(0));
+        assert_eq!(
+            compared,
+            [
+                (Some(("left", "2")), Some("1 2".to_string())),
+                (Some(("2", "left - 1")), Some("2 0".to_string())),
+                (None, None),
+                (None, Some("1 2".to_string()))
+            ]
+        );
+    }
+
+    // Without `std` feature, messages are formatted by `core`, so the payloads are always `String`.
+    #[test]
+    #[cfg(feature = "std")]
+    fn keeps_panic_message() {
+        let _recording = Recording::start();
+        let payload = catch_unwind(|| crate::assert_none!(Some(1))).unwrap_err();
+        assert_eq!(
+            payload.downcast_ref::<String>().map(|s| s.as_str()),
+            Some("assertion failed, expected None, got Some(1)")
+        );
+
+        let payload = catch_unwind(|| crate::assert_some!(None::<i32>)).unwrap_err();
+        assert_eq!(
+            payload.downcast_ref::<&str>(),
+            Some(&"assertion failed, expected Some(..), got None")
+        );
+    }
+}
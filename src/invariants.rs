@@ -0,0 +1,557 @@
+//! Runtime invariants, which are checked in release builds and reported instead of panicking.
+//!
+//! This module is available for Rust 1.63+ with `std` feature,
+//! and the `ensure_*` and `expect_*` macros are available for Rust 1.65+.
+//!
+//! Every assertion macro of this crate, except for the compile-time [`assert_is`],
+//! has the `ensure_*` and `expect_*` counterparts, ex. [`ensure_ok`] and [`expect_ok`].
+//! They check the same conditions in all builds, but a failure does not panic:
+//! it is passed to the [`Sink`] with the same message, counted in [`violations`],
+//! and returned to the caller, so the program can recover and keep running.
+//!
+//! * `ensure_*` macros return `Some` with the value of the assertion or `None` on failure;
+//! * `expect_*` macros return `Ok` with the value of the assertion or `Err` with the [`Violation`].
+//!
+//! With `log` and `tracing` features, violations are emitted as the same events, as the failed assertions,
+//! before they are passed to the sink. The default sink writes them to `stderr` without these features
+//! and does nothing with them. Another one can be set with [`set_sink`].
+//!
+//! These macros check the condition in place and return the violation without unwinding,
+//! so they work with `panic = "abort"` too, and cost no more than the assertions themselves.
+//!
+//! Any other code, including the assertions defined with the [`define_assertion`] macro,
+//! can be checked with the [`check`] function. Failed assertions of this crate within it
+//! are reported the same way, while other panics are propagated to the caller.
+//! Unlike the macros, it returns violations by unwinding the stack from the failed assertion,
+//! so it requires the default `panic = "unwind"` strategy.
+//! With `panic = "abort"`, the process is aborted after the violation is passed to the sink.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! # #[macro_use] extern crate claim;
+//! use claim::invariants::{set_sink, violations, Violation};
+//!
+//! fn report(violation: &Violation) {
+//!     eprintln!("{} failed at {}: {}", violation.assertion(), violation.location(), violation);
+//! }
+//!
+//! fn average(values: &[u32]) -> u32 {
+//!     match ensure_gt!(values.len(), 0, "no values to average") {
+//!         Some(()) => values.iter().sum::<u32>() / values.len() as u32,
+//!         None => 0,
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let _ = set_sink(report);
+//! assert_eq!(average(&[1, 2, 3]), 2);
+//! assert_eq!(average(&[]), 0);  // Will be reported and return the fallback value
+//! assert!(violations() >= 1);
+//!
+//! let error = expect_some!(None::<i32>).unwrap_err();
+//! assert_eq!(error.assertion(), "assert_some");
+//! assert_eq!(error.message(), "assertion failed, expected Some(..), got None");
+//! # }
+//! ```
+//!
+//! [`assert_is`]: ../macro.assert_is.html
+//! [`ensure_ok`]: ../macro.ensure_ok.html
+//! [`expect_ok`]: ../macro.expect_ok.html
+//! [`define_assertion`]: ../macro.define_assertion.html
+//! [`Sink`]: ./type.Sink.html
+//! [`Violation`]: ./struct.Violation.html
+//! [`violations`]: ./fn.violations.html
+//! [`set_sink`]: ./fn.set_sink.html
+//! [`check`]: ./fn.check.html
+
+use core::cell::Cell;
+use core::fmt;
+use core::panic::Location;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::boxed::Box;
+use std::error::Error;
+use std::panic::{self, AssertUnwindSafe};
+use std::string::{String, ToString};
+use std::sync::RwLock;
+
+use handler::Failure;
+
+/// Function, which is called with the violated invariant record.
+pub type Sink = fn(&Violation);
+
+static SINK: RwLock<Option<Sink>> = RwLock::new(None);
+
+static VIOLATIONS: AtomicUsize = AtomicUsize::new(0);
+
+std::thread_local! {
+    // Number of the nested `check` calls on the current thread.
+    static CHECKING: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Record of the violated invariant.
+///
+/// Its `Display` implementation writes the same message, which the assertion panics with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    assertion: &'static str,
+    message: String,
+    location: &'static Location<'static>,
+}
+
+impl Violation {
+    /// Returns name of the failed assertion, ex. `assert_ok`.
+    pub fn assertion(&self) -> &'static str {
+        self.assertion
+    }
+
+    /// Returns the failure message, including the custom message, if any.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns location of the failed assertion.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for Violation {}
+
+/// Sets the sink of the violated invariants and returns the previous one.
+pub fn set_sink(sink: Sink) -> Option<Sink> {
+    let mut current = SINK.write().unwrap_or_else(|error| error.into_inner());
+    current.replace(sink)
+}
+
+/// Removes the sink of the violated invariants and returns it,
+/// so the default one is used.
+pub fn take_sink() -> Option<Sink> {
+    let mut current = SINK.write().unwrap_or_else(|error| error.into_inner());
+    current.take()
+}
+
+/// Returns the number of violated invariants since the program start.
+pub fn violations() -> usize {
+    VIOLATIONS.load(Ordering::Relaxed)
+}
+
+/// Calls the function and returns its result or the first violated invariant.
+///
+/// Failed assertions of this crate within the function are passed to the sink
+/// instead of the failure handler, and returned as the [`Violation`] instead of panicking.
+/// Other panics are propagated to the caller.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use claim::invariants::check;
+///
+/// # fn main() {
+/// let checked = check(|| {
+///     assert_ge!(2, 1);
+///     assert_some!(None::<i32>)
+/// });
+/// assert_eq!(checked.unwrap_err().assertion(), "assert_some");
+/// # }
+/// ```
+///
+/// [`Violation`]: ./struct.Violation.html
+pub fn check<T, F>(f: F) -> Result<T, Violation>
+where
+    F: FnOnce() -> T,
+{
+    let _checking = Checking::enter();
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => Ok(value),
+        Err(payload) => match payload.downcast::<Violation>() {
+            Ok(violation) => Err(*violation),
+            Err(payload) => panic::resume_unwind(payload),
+        },
+    }
+}
+
+/// Marks the current thread as checking the invariants until dropped.
+struct Checking;
+
+impl Checking {
+    fn enter() -> Self {
+        CHECKING.with(|checking| checking.set(checking.get() + 1));
+        Checking
+    }
+}
+
+impl Drop for Checking {
+    fn drop(&mut self) {
+        CHECKING.with(|checking| checking.set(checking.get() - 1));
+    }
+}
+
+/// Returns `true` if the failures are checked as invariants on the current thread.
+pub(crate) fn is_checking() -> bool {
+    CHECKING.with(|checking| checking.get() > 0)
+}
+
+/// Passes the failure to the sink and returns it as the violation.
+pub(crate) fn violation(failure: &Failure<'_>) -> Violation {
+    let violation = Violation {
+        assertion: failure.assertion(),
+        message: failure.to_string(),
+        location: failure.location(),
+    };
+    let _ = VIOLATIONS.fetch_add(1, Ordering::Relaxed);
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    ::events::emit(failure);
+    let sink = *SINK.read().unwrap_or_else(|error| error.into_inner());
+    match sink {
+        Some(sink) => sink(&violation),
+        None => report(failure),
+    }
+    violation
+}
+
+/// Passes the failure to the sink and unwinds to the enclosing `check` call.
+pub(crate) fn unwind(failure: &Failure<'_>) -> ! {
+    // Unlike `panic!`, it does not call the panic hook, which would print the message again.
+    panic::resume_unwind(Box::new(violation(failure)))
+}
+
+/// Default sink, which does nothing, as the `log` and `tracing` events of the failure are already emitted.
+#[cfg(any(feature = "log", feature = "tracing"))]
+fn report(_failure: &Failure<'_>) {}
+
+/// Default sink, which writes the failure to `stderr`.
+#[cfg(not(any(feature = "log", feature = "tracing")))]
+fn report(failure: &Failure<'_>) {
+    std::eprintln!(
+        "{} failed at {}: {}",
+        failure.assertion(),
+        failure.location(),
+        failure
+    );
+}
+
+/// Defines the `ensure_*` and `expect_*` counterparts of the assertion macros.
+///
+/// The `$` token is passed as the first argument,
+/// so it can be used for the metavariables of the defined macros.
+macro_rules! define_invariants {
+    (($d:tt) $($assert:ident => $ensure:ident, $expect:ident;)+) => {
+        $(
+            #[doc = concat!(
+                "Checks the same condition as [`", stringify!($assert), "!`](./macro.",
+                stringify!($assert), ".html), but returns `Some` with its value or `None` on failure.\n\n",
+                "The failure is reported to the sink of the [`invariants`](./invariants/index.html) module ",
+                "instead of panicking.\n\n",
+                "This macro is available for Rust 1.65+ with `std` feature.",
+            )]
+            #[macro_export]
+            macro_rules! $ensure {
+                ($d($d arg:tt)+) => {
+                    Result::ok('claim_ensure: { Ok($crate::$assert!(@fail ['claim_ensure] $d($d arg)+)) })
+                };
+            }
+
+            #[doc = concat!(
+                "Checks the same condition as [`", stringify!($assert), "!`](./macro.",
+                stringify!($assert), ".html), but returns `Ok` with its value or `Err` with the ",
+                "[`Violation`](./invariants/struct.Violation.html) on failure.\n\n",
+                "The failure is reported to the sink of the [`invariants`](./invariants/index.html) module ",
+                "instead of panicking.\n\n",
+                "This macro is available for Rust 1.65+ with `std` feature.",
+            )]
+            #[macro_export]
+            macro_rules! $expect {
+                ($d($d arg:tt)+) => {
+                    'claim_expect: { Ok($crate::$assert!(@fail ['claim_expect] $d($d arg)+)) }
+                };
+            }
+        )+
+    };
+}
+
+#[cfg(rustc_1_65)]
+define_invariants! {
+    ($)
+    assert_ge => ensure_ge, expect_ge;
+    assert_gt => ensure_gt, expect_gt;
+    assert_le => ensure_le, expect_le;
+    assert_lt => ensure_lt, expect_lt;
+    assert_cmp => ensure_cmp, expect_cmp;
+    assert_all_close => ensure_all_close, expect_all_close;
+    assert_bytes_eq => ensure_bytes_eq, expect_bytes_eq;
+    assert_bits_set => ensure_bits_set, expect_bits_set;
+    assert_bits_clear => ensure_bits_clear, expect_bits_clear;
+    assert_bits_eq => ensure_bits_eq, expect_bits_eq;
+    assert_overflows => ensure_overflows, expect_overflows;
+    assert_no_overflow => ensure_no_overflow, expect_no_overflow;
+    assert_power_of_two => ensure_power_of_two, expect_power_of_two;
+    assert_aligned => ensure_aligned, expect_aligned;
+    assert_multiple_of => ensure_multiple_of, expect_multiple_of;
+    assert_matches => ensure_matches, expect_matches;
+    assert_not_matches => ensure_not_matches, expect_not_matches;
+    assert_variant => ensure_variant, expect_variant;
+    assert_same_variant => ensure_same_variant, expect_same_variant;
+    assert_that => ensure_that, expect_that;
+    assert_fields => ensure_fields, expect_fields;
+    assert_downcast => ensure_downcast, expect_downcast;
+    assert_ok => ensure_ok, expect_ok;
+    assert_err => ensure_err, expect_err;
+    assert_ok_eq => ensure_ok_eq, expect_ok_eq;
+    assert_ok_ne => ensure_ok_ne, expect_ok_ne;
+    assert_io_err => ensure_io_err, expect_io_err;
+    assert_os_error => ensure_os_error, expect_os_error;
+    assert_some => ensure_some, expect_some;
+    assert_none => ensure_none, expect_none;
+    assert_some_eq => ensure_some_eq, expect_some_eq;
+    assert_some_ne => ensure_some_ne, expect_some_ne;
+    assert_all => ensure_all, expect_all;
+    assert_any => ensure_any, expect_any;
+    assert_all_match => ensure_all_match, expect_all_match;
+    assert_none_match => ensure_none_match, expect_none_match;
+    assert_unique => ensure_unique, expect_unique;
+    assert_unique_by_key => ensure_unique_by_key, expect_unique_by_key;
+    assert_prefix => ensure_prefix, expect_prefix;
+    assert_suffix => ensure_suffix, expect_suffix;
+    assert_subsequence => ensure_subsequence, expect_subsequence;
+    assert_subset => ensure_subset, expect_subset;
+    assert_superset => ensure_superset, expect_superset;
+    assert_disjoint => ensure_disjoint, expect_disjoint;
+    assert_set_eq => ensure_set_eq, expect_set_eq;
+    assert_contains_key => ensure_contains_key, expect_contains_key;
+    assert_map_entry => ensure_map_entry, expect_map_entry;
+    assert_map_eq => ensure_map_eq, expect_map_eq;
+    assert_ready => ensure_ready, expect_ready;
+    assert_ready_ok => ensure_ready_ok, expect_ready_ok;
+    assert_ready_err => ensure_ready_err, expect_ready_err;
+    assert_pending => ensure_pending, expect_pending;
+    assert_ready_eq => ensure_ready_eq, expect_ready_eq;
+    assert_ready_ne => ensure_ready_ne, expect_ready_ne;
+    assert_ready_ok_eq => ensure_ready_ok_eq, expect_ready_ok_eq;
+    assert_ready_err_eq => ensure_ready_err_eq, expect_ready_err_eq;
+    assert_ready_some_eq => ensure_ready_some_eq, expect_ready_some_eq;
+}
+
+#[cfg(all(rustc_1_65, feature = "power-assert"))]
+define_invariants! {
+    ($)
+    claim => ensure_claim, expect_claim;
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+pub(crate) mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::panic::{catch_unwind, Location};
+    use std::string::{String, ToString};
+    use std::sync::Mutex;
+    use std::vec::Vec;
+
+    use super::{check, set_sink, take_sink, violations, Sink, Violation};
+
+    std::thread_local! {
+        static VIOLATIONS: RefCell<Vec<Violation>> = const { RefCell::new(Vec::new()) };
+    }
+
+    // Sink is global, so it only records violations of the current thread.
+    fn record(violation: &Violation) {
+        VIOLATIONS.with(|violations| violations.borrow_mut().push(violation.clone()));
+    }
+
+    // Number of the running tests, which record violations, and the sink to restore after them.
+    static RECORDING: Mutex<(usize, Option<Sink>)> = Mutex::new((0, None));
+
+    /// Sets the recording sink and restores the previous one, when the last test is finished.
+    struct Recording;
+
+    impl Recording {
+        fn start() -> Self {
+            let mut recording = RECORDING.lock().unwrap_or_else(|error| error.into_inner());
+            if recording.0 == 0 {
+                recording.1 = set_sink(record);
+            }
+            recording.0 += 1;
+            Recording
+        }
+    }
+
+    impl Drop for Recording {
+        fn drop(&mut self) {
+            let mut recording = RECORDING.lock().unwrap_or_else(|error| error.into_inner());
+            recording.0 -= 1;
+            if recording.0 == 0 {
+                let _ = match recording.1.take() {
+                    Some(previous) => set_sink(previous),
+                    None => take_sink(),
+                };
+            }
+        }
+    }
+
+    /// Calls the function and returns its result with the violations recorded on the current thread.
+    pub(crate) fn recorded<T, F: FnOnce() -> T>(f: F) -> (T, Vec<Violation>) {
+        let _recording = Recording::start();
+        VIOLATIONS.with(|violations| violations.borrow_mut().clear());
+        let value = f();
+        (
+            value,
+            VIOLATIONS.with(|violations| violations.borrow_mut().split_off(0)),
+        )
+    }
+
+    #[test]
+    fn passes() {
+        let (values, violations) = recorded(|| {
+            (
+                ensure_ok!(Ok::<i32, ()>(1)),
+                ensure_lt!(1, 2),
+                expect_some_eq!(Some(3), 3),
+                expect_matches!(Some(4), Some(_)),
+            )
+        });
+        assert_eq!(values, (Some(1), Some(()), Ok(3), Ok(())));
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn reports_violations() {
+        let before = violations();
+        let mut line = 0;
+        let (value, recorded) = recorded(|| {
+            line = Location::caller().line() + 1;
+            ensure_some!(None::<i32>, "custom {}", "message")
+        });
+        assert_eq!(value, None);
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].assertion(), "assert_some");
+        assert_eq!(
+            recorded[0].message(),
+            "assertion failed, expected Some(..), got None: custom message"
+        );
+        assert_eq!(recorded[0].location().line(), line);
+        assert!(violations() > before);
+    }
+
+    #[test]
+    fn returns_violations() {
+        let (result, recorded) = recorded(|| expect_ok_eq!(Ok::<i32, ()>(1), 2));
+        let violation = result.unwrap_err();
+        assert_eq!(violation.assertion(), "assert_ok_eq");
+        assert_eq!(
+            violation.to_string(),
+            "assertion failed: `(left == right)`\n    left: `1`,\n    right: `2`"
+        );
+        assert_eq!(recorded, [violation]);
+    }
+
+    #[test]
+    fn checks_first_violation() {
+        let (result, recorded) = recorded(|| {
+            check(|| {
+                crate::assert_ge!(1, 2);
+                let _ = crate::assert_none!(Some(1));
+            })
+        });
+        assert_eq!(result.unwrap_err().assertion(), "assert_ge");
+        assert_eq!(recorded.len(), 1);
+    }
+
+    #[test]
+    fn checks_nested() {
+        let (result, recorded) = recorded(|| {
+            check(|| {
+                let inner = expect_none!(Some(1));
+                let _ = crate::assert_err!(inner);
+                crate::assert_some!(None::<i32>)
+            })
+        });
+        assert_eq!(result.unwrap_err().assertion(), "assert_some");
+        assert_eq!(recorded.len(), 2);
+    }
+
+    #[test]
+    fn reports_io_error_assertions() {
+        use std::io;
+
+        let evaluated = Cell::new(0);
+        let kind = || {
+            evaluated.set(evaluated.get() + 1);
+            io::ErrorKind::NotFound
+        };
+        let code = || {
+            evaluated.set(evaluated.get() + 1);
+            2
+        };
+        let (results, recorded) = recorded(|| {
+            (
+                expect_io_err!(Ok::<(), io::Error>(()), kind()).is_err(),
+                expect_io_err!(Err::<(), _>(io::Error::other("oops")), kind()).is_err(),
+                expect_os_error!(Ok::<(), io::Error>(()), code()).is_err(),
+                expect_os_error!(Err::<(), _>(io::Error::from_raw_os_error(13)), code()).is_err(),
+            )
+        });
+        assert_eq!(results, (true, true, true, true));
+        assert_eq!(evaluated.get(), 4);
+        let assertions: Vec<_> = recorded.iter().map(Violation::assertion).collect();
+        assert_eq!(
+            assertions,
+            [
+                "assert_io_err",
+                "assert_io_err",
+                "assert_os_error",
+                "assert_os_error"
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_without_unwinding() {
+        // Temporaries of the checked expression are dropped after the failure is reported,
+        // so the probe sees whether it happens while unwinding.
+        struct Probe<'a>(&'a Cell<Option<bool>>);
+
+        impl<'a> Probe<'a> {
+            fn value(&self) -> Option<i32> {
+                None
+            }
+        }
+
+        impl<'a> Drop for Probe<'a> {
+            fn drop(&mut self) {
+                self.0.set(Some(std::thread::panicking()));
+            }
+        }
+
+        let unwinding = Cell::new(None);
+        let (value, recorded) = recorded(|| ensure_some!(Probe(&unwinding).value()));
+        assert_eq!(value, None);
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(unwinding.get(), Some(false));
+    }
+
+    #[test]
+    fn propagates_panics() {
+        let _recording = Recording::start();
+        let payload =
+            catch_unwind(|| ensure_all!([1, 2], |_| -> bool { panic!("oops") })).unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"oops"));
+    }
+
+    #[test]
+    fn panics_outside_of_check() {
+        let _recording = Recording::start();
+        let _ = ensure_ok!(Err::<(), String>("error".to_string()));
+        let payload = catch_unwind(|| crate::assert_some!(None::<i32>)).unwrap_err();
+        assert_eq!(
+            payload.downcast_ref::<&str>(),
+            Some(&"assertion failed, expected Some(..), got None")
+        );
+    }
+}
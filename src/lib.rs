@@ -17,9 +17,10 @@
     trivial_casts,
     trivial_numeric_casts,
     unused_import_braces,
-    unused_results,
-    unsafe_code
+    unused_results
 )]
+// The failure handler is stored as an address, which is converted back in a single allowed function.
+#![deny(unsafe_code)]
 
 //! Assertion macros.
 //!
@@ -29,10 +30,44 @@
 //!
 //! `claim` can be used in a `no-std` environments too.
 //!
+//! Enable the `alloc` feature to extend some of the assertions
+//! to the `alloc` crate types, such as `Vec` and `String`,
+//! and the `std` feature for the `std` crate types, such as `HashMap`.
+//!
+//! The `power-assert` feature enables the [`claim`] macro (requires Rust 1.71+).
+//!
+//! ## Failure handler
+//!
+//! Failed assertions panic by default.
+//! On Rust 1.63+, failures can also be passed to the handler set with the [`handler::set_handler`]
+//! function, ex. to report or log them before the panic, including `no_std` builds.
+//!
+//! With `log` or `tracing` features (together with `std` feature), every failure is also emitted as a `log` record
+//! or a `tracing` event before the panic, on the `ERROR` level with the `claim` target.
+//! Its message is the same as the panic message, prefixed with the assertion name and location,
+//! and it has the following fields:
+//!
+//! * `assertion`: name of the failed assertion, ex. `assert_ge`;
+//! * `file`, `line` and `column`: location of the failed assertion;
+//! * `left_expression` and `right_expression`: source code of the compared expressions
+//!   for [`assert_ge`], [`assert_gt`], [`assert_le`], [`assert_lt`] and [`assert_cmp`];
+//! * `left` and `right`: values of the compared expressions for the assertions above
+//!   and the `*_eq` and `*_ne` assertions.
+//!
+//! ## Runtime invariants
+//!
+//! With `std` feature on Rust 1.65+, all assertions with runtime checks also have
+//! the `ensure_*` and `expect_*` counterparts, ex. [`ensure_ok`] and [`expect_ok`].
+//! They are checked in release builds too, but instead of panicking, the failure is passed
+//! to the sink of the [`invariants`] module (and emitted as the same events with `log` or `tracing` features)
+//! and returned as `None` or `Err` respectively without unwinding, so the caller can recover
+//! even with `panic = "abort"`.
+//!
 //! ## Available macros
 //!
 //! Note that same to [`core`]/[`std`] macros,
-//! all macros in this crate has the [`debug_*`](#macros) counterparts.
+//! all macros in this crate has the [`debug_*`](#macros) counterparts,
+//! except for the compile-time [`assert_is`].
 //!
 //! ### Comparison
 //!
@@ -43,9 +78,62 @@
 //! * [`assert_le`]
 //! * [`assert_lt`]
 //!
+//! Any of the comparisons above, including chained ones, dispatched on the operator:
+//!
+//! * [`assert_cmp`]
+//!
+//! Element-wise approximate equality for floating point collections:
+//!
+//! * [`assert_all_close`]
+//!
+//! Equality of bytes with a hexdump diff:
+//!
+//! * [`assert_bytes_eq`]
+//!
+//! ### Bit macros
+//!
+//! Assertions for flags and masks of integers:
+//!
+//! * [`assert_bits_set`]
+//! * [`assert_bits_clear`]
+//! * [`assert_bits_eq`]
+//!
+//! ### Arithmetic macros
+//!
+//! Assertions for checked arithmetic, which report the operands on failure,
+//! and for the properties of integers and addresses:
+//!
+//! * [`assert_overflows`]
+//! * [`assert_no_overflow`]
+//! * [`assert_power_of_two`]
+//! * [`assert_aligned`]
+//! * [`assert_multiple_of`]
+//!
 //! ### Matching
 //!
 //! * [`assert_matches`]
+//! * [`assert_not_matches`]
+//! * [`assert_variant`]
+//! * [`assert_same_variant`]
+//!
+//! ### Power assertions
+//!
+//! Assertion for any boolean expression, which prints values
+//! of all its sub-expressions on failure (with `power-assert` feature):
+//!
+//! * [`claim`]
+//!
+//! ### Matchers
+//!
+//! Assertions with composable [`matchers`]:
+//!
+//! * [`assert_that`]
+//! * [`assert_fields`]
+//!
+//! ### Type macros
+//!
+//! * [`assert_downcast`]
+//! * [`assert_is`]
 //!
 //! ### `Result` macros
 //!
@@ -54,6 +142,12 @@
 //! * [`assert_ok`]
 //! * [`assert_err`]
 //! * [`assert_ok_eq`]
+//! * [`assert_ok_ne`]
+//!
+//! Assertions for [`io::Error`] (with `std` feature):
+//!
+//! * [`assert_io_err`]
+//! * [`assert_os_error`]
 //!
 //! ### `Option` macros
 //!
@@ -62,6 +156,46 @@
 //! * [`assert_some`]
 //! * [`assert_none`]
 //! * [`assert_some_eq`]
+//! * [`assert_some_ne`]
+//!
+//! ### Predicate macros
+//!
+//! Assertions for every element of arrays, slices, collections and iterators:
+//!
+//! * [`assert_all`]
+//! * [`assert_any`]
+//! * [`assert_all_match`]
+//! * [`assert_none_match`]
+//!
+//! Uniqueness assertions (with `alloc` feature):
+//!
+//! * [`assert_unique`]
+//! * [`assert_unique_by_key`]
+//!
+//! ### Sequence macros
+//!
+//! Assertions for the ordered elements of arrays, slices, collections and iterators:
+//!
+//! * [`assert_prefix`]
+//! * [`assert_suffix`]
+//! * [`assert_subsequence`]
+//!
+//! ### Set macros
+//!
+//! Assertions for elements of arrays, slices, collections and iterators, regardless of their order:
+//!
+//! * [`assert_subset`]
+//! * [`assert_superset`]
+//! * [`assert_disjoint`]
+//! * [`assert_set_eq`]
+//!
+//! ### Map macros
+//!
+//! Assertions for [`BTreeMap`] (with `alloc` feature) and [`HashMap`] (with `std` feature):
+//!
+//! * [`assert_contains_key`]
+//! * [`assert_map_entry`]
+//! * [`assert_map_eq`]
 //!
 //! ### `Poll` macros
 //!
@@ -70,8 +204,29 @@
 //! * [`assert_ready`]
 //! * [`assert_ready_ok`]
 //! * [`assert_ready_err`]
-//! * [`assert_ready_pending`]
+//! * [`assert_pending`]
 //! * [`assert_ready_eq`]
+//! * [`assert_ready_ne`]
+//! * [`assert_ready_ok_eq`]
+//! * [`assert_ready_err_eq`]
+//! * [`assert_ready_some_eq`]
+//!
+//! ### Method-style assertions
+//!
+//! The [`fluent::Claim`] trait provides the `claim_*` methods, which mirror the macros above
+//! and return the checked values, so they can be chained (requires Rust 1.46+):
+//!
+//! ```rust
+//! use claim::fluent::Claim;
+//!
+//! let res: Result<Option<i32>, ()> = Ok(Some(5));
+//! res.claim_ok().claim_some().claim_gt(&3);
+//! ```
+//!
+//! ### Custom assertions
+//!
+//! Assertions for your own types, with the same conventions as the macros above,
+//! can be defined with the [`define_assertion`] macro.
 //!
 //! [`core`]: https://doc.rust-lang.org/stable/core/#macros
 //! [`std`]: https://doc.rust-lang.org/stable/std/#macros
@@ -84,18 +239,89 @@
 //! [`assert_gt`]: ./macro.assert_gt.html
 //! [`assert_le`]: ./macro.assert_le.html
 //! [`assert_lt`]: ./macro.assert_lt.html
+//! [`assert_cmp`]: ./macro.assert_cmp.html
+//! [`assert_all_close`]: ./macro.assert_all_close.html
+//! [`assert_bytes_eq`]: ./macro.assert_bytes_eq.html
+//! [`assert_bits_set`]: ./macro.assert_bits_set.html
+//! [`assert_bits_clear`]: ./macro.assert_bits_clear.html
+//! [`assert_bits_eq`]: ./macro.assert_bits_eq.html
+//! [`assert_overflows`]: ./macro.assert_overflows.html
+//! [`assert_no_overflow`]: ./macro.assert_no_overflow.html
+//! [`assert_power_of_two`]: ./macro.assert_power_of_two.html
+//! [`assert_aligned`]: ./macro.assert_aligned.html
+//! [`assert_multiple_of`]: ./macro.assert_multiple_of.html
+//! [`assert_downcast`]: ./macro.assert_downcast.html
+//! [`assert_is`]: ./macro.assert_is.html
 //! [`assert_some`]: ./macro.assert_some.html
 //! [`assert_none`]: ./macro.assert_none.html
 //! [`assert_some_eq`]: ./macro.assert_some_eq.html
+//! [`assert_some_ne`]: ./macro.assert_some_ne.html
 //! [`assert_ok`]: ./macro.assert_ok.html
 //! [`assert_err`]: ./macro.assert_err.html
 //! [`assert_ok_eq`]: ./macro.assert_ok_eq.html
+//! [`assert_ok_ne`]: ./macro.assert_ok_ne.html
+//! [`assert_all`]: ./macro.assert_all.html
+//! [`assert_any`]: ./macro.assert_any.html
+//! [`assert_all_match`]: ./macro.assert_all_match.html
+//! [`assert_none_match`]: ./macro.assert_none_match.html
+//! [`assert_unique`]: ./macro.assert_unique.html
+//! [`assert_unique_by_key`]: ./macro.assert_unique_by_key.html
+//! [`assert_prefix`]: ./macro.assert_prefix.html
+//! [`assert_suffix`]: ./macro.assert_suffix.html
+//! [`assert_subsequence`]: ./macro.assert_subsequence.html
+//! [`assert_subset`]: ./macro.assert_subset.html
+//! [`assert_superset`]: ./macro.assert_superset.html
+//! [`assert_disjoint`]: ./macro.assert_disjoint.html
+//! [`assert_set_eq`]: ./macro.assert_set_eq.html
+//! [`BTreeMap`]: https://doc.rust-lang.org/alloc/collections/btree_map/struct.BTreeMap.html
+//! [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+//! [`assert_contains_key`]: ./macro.assert_contains_key.html
+//! [`assert_map_entry`]: ./macro.assert_map_entry.html
+//! [`assert_map_eq`]: ./macro.assert_map_eq.html
+//! [`io::Error`]: https://doc.rust-lang.org/std/io/struct.Error.html
+//! [`assert_io_err`]: ./macro.assert_io_err.html
+//! [`assert_os_error`]: ./macro.assert_os_error.html
 //! [`assert_ready`]: ./macro.assert_ready.html
 //! [`assert_ready_ok`]: ./macro.assert_ready_ok.html
 //! [`assert_ready_err`]: ./macro.assert_ready_err.html
-//! [`assert_ready_pending`]: ./macro.assert_ready_pending.html
+//! [`assert_pending`]: ./macro.assert_pending.html
 //! [`assert_ready_eq`]: ./macro.assert_ready_eq.html
+//! [`assert_ready_ne`]: ./macro.assert_ready_ne.html
+//! [`assert_ready_ok_eq`]: ./macro.assert_ready_ok_eq.html
+//! [`assert_ready_err_eq`]: ./macro.assert_ready_err_eq.html
+//! [`assert_ready_some_eq`]: ./macro.assert_ready_some_eq.html
 //! [`assert_matches`]: ./macro.assert_matches.html
+//! [`assert_not_matches`]: ./macro.assert_not_matches.html
+//! [`assert_variant`]: ./macro.assert_variant.html
+//! [`assert_same_variant`]: ./macro.assert_same_variant.html
+//! [`claim`]: ./macro.claim.html
+//! [`matchers`]: ./matchers/index.html
+//! [`assert_that`]: ./macro.assert_that.html
+//! [`assert_fields`]: ./macro.assert_fields.html
+//! [`define_assertion`]: ./macro.define_assertion.html
+//! [`fluent::Claim`]: ./fluent/trait.Claim.html
+//! [`handler::set_handler`]: ./handler/fn.set_handler.html
+//! [`invariants`]: ./invariants/index.html
+//! [`ensure_ok`]: ./macro.ensure_ok.html
+//! [`expect_ok`]: ./macro.expect_ok.html
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "power-assert")]
+extern crate claim_macros;
+#[cfg(feature = "log")]
+extern crate log;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+#[cfg(feature = "tracing")]
+extern crate tracing;
+
+// Features of the optional dependencies can't enable `std` feature on the older Cargo versions.
+#[cfg(all(any(feature = "log", feature = "tracing"), not(feature = "std")))]
+compile_error!("`log` and `tracing` features require `std` feature");
+
+#[macro_use]
+mod failure;
 
 mod assert_err;
 mod assert_ge;
@@ -105,8 +331,10 @@ mod assert_lt;
 mod assert_none;
 mod assert_ok;
 mod assert_ok_eq;
+mod assert_ok_ne;
 mod assert_some;
 mod assert_some_eq;
+mod assert_some_ne;
 
 #[cfg(has_task_poll)]
 mod assert_pending;
@@ -117,7 +345,111 @@ mod assert_ready_eq;
 #[cfg(has_task_poll)]
 mod assert_ready_err;
 #[cfg(has_task_poll)]
+mod assert_ready_err_eq;
+#[cfg(has_task_poll)]
+mod assert_ready_ne;
+#[cfg(has_task_poll)]
 mod assert_ready_ok;
+#[cfg(has_task_poll)]
+mod assert_ready_ok_eq;
+#[cfg(has_task_poll)]
+mod assert_ready_some_eq;
+
+#[cfg(feature = "power-assert")]
+mod claim;
 
 #[cfg(rustc_1_26)]
 mod assert_matches;
+#[cfg(rustc_1_26)]
+mod assert_not_matches;
+#[cfg(rustc_1_31)]
+mod assert_same_variant;
+#[cfg(rustc_1_31)]
+mod assert_variant;
+
+#[cfg(rustc_1_31)]
+#[macro_use]
+pub mod matchers;
+#[cfg(rustc_1_31)]
+mod assert_aligned;
+#[cfg(rustc_1_31)]
+mod assert_all;
+#[cfg(rustc_1_31)]
+mod assert_all_close;
+#[cfg(rustc_1_31)]
+mod assert_all_match;
+#[cfg(rustc_1_31)]
+mod assert_any;
+#[cfg(rustc_1_31)]
+mod assert_bits_clear;
+#[cfg(rustc_1_31)]
+mod assert_bits_eq;
+#[cfg(rustc_1_31)]
+mod assert_bits_set;
+#[cfg(rustc_1_31)]
+mod assert_bytes_eq;
+#[cfg(rustc_1_31)]
+mod assert_cmp;
+#[cfg(rustc_1_31)]
+mod assert_disjoint;
+#[cfg(rustc_1_31)]
+mod assert_downcast;
+#[cfg(rustc_1_31)]
+mod assert_fields;
+#[cfg(rustc_1_31)]
+mod assert_is;
+#[cfg(rustc_1_31)]
+mod assert_multiple_of;
+#[cfg(rustc_1_31)]
+mod assert_no_overflow;
+#[cfg(rustc_1_31)]
+mod assert_none_match;
+#[cfg(rustc_1_31)]
+mod assert_overflows;
+#[cfg(rustc_1_31)]
+mod assert_power_of_two;
+#[cfg(rustc_1_31)]
+mod assert_prefix;
+#[cfg(rustc_1_31)]
+mod assert_set_eq;
+#[cfg(rustc_1_31)]
+mod assert_subsequence;
+#[cfg(rustc_1_31)]
+mod assert_subset;
+#[cfg(rustc_1_31)]
+mod assert_suffix;
+#[cfg(rustc_1_31)]
+mod assert_superset;
+#[cfg(rustc_1_31)]
+mod assert_that;
+#[cfg(rustc_1_31)]
+mod define_assertion;
+
+#[cfg(all(rustc_1_31, feature = "alloc"))]
+mod assert_contains_key;
+#[cfg(all(rustc_1_31, feature = "alloc"))]
+mod assert_map_entry;
+#[cfg(all(rustc_1_31, feature = "alloc"))]
+mod assert_map_eq;
+#[cfg(all(rustc_1_31, feature = "alloc"))]
+mod assert_unique;
+#[cfg(all(rustc_1_31, feature = "alloc"))]
+mod assert_unique_by_key;
+
+#[cfg(all(rustc_1_31, feature = "std"))]
+mod assert_io_err;
+#[cfg(all(rustc_1_31, feature = "std"))]
+mod assert_os_error;
+
+#[cfg(all(rustc_1_63, any(feature = "log", feature = "tracing")))]
+mod events;
+#[cfg(rustc_1_46)]
+pub mod fluent;
+#[cfg(rustc_1_63)]
+pub mod handler;
+#[cfg(all(rustc_1_63, feature = "std"))]
+pub mod invariants;
+
+#[cfg(rustc_1_31)]
+#[doc(hidden)]
+pub mod __private;
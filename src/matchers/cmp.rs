@@ -0,0 +1,68 @@
+use core::fmt;
+
+use super::Matcher;
+
+macro_rules! comparison_matcher {
+    ($(#[$meta:meta])* $name:ident, $func:ident, $bound:ident, $op:tt, $description:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $name<U>(U);
+
+        $(#[$meta])*
+        pub fn $func<U>(expected: U) -> $name<U> {
+            $name(expected)
+        }
+
+        impl<T: ?Sized + $bound<U>, U: fmt::Debug> Matcher<T> for $name<U> {
+            fn matches(&self, actual: &T) -> bool {
+                *actual $op self.0
+            }
+
+            fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} {:?}", $description, self.0)
+            }
+        }
+    };
+}
+
+comparison_matcher!(
+    /// Matches values equal to the expected one, same as [`assert_eq!`] does.
+    ///
+    /// [`assert_eq!`]: https://doc.rust-lang.org/std/macro.assert_eq.html
+    Eq, eq, PartialEq, ==, "equal to"
+);
+
+comparison_matcher!(
+    /// Matches values not equal to the expected one, same as [`assert_ne!`] does.
+    ///
+    /// [`assert_ne!`]: https://doc.rust-lang.org/std/macro.assert_ne.html
+    Ne, ne, PartialEq, !=, "not equal to"
+);
+
+comparison_matcher!(
+    /// Matches values greater than the expected one, same as [`assert_gt!`] does.
+    ///
+    /// [`assert_gt!`]: ../macro.assert_gt.html
+    Gt, gt, PartialOrd, >, "greater than"
+);
+
+comparison_matcher!(
+    /// Matches values greater or equal than the expected one, same as [`assert_ge!`] does.
+    ///
+    /// [`assert_ge!`]: ../macro.assert_ge.html
+    Ge, ge, PartialOrd, >=, "greater or equal than"
+);
+
+comparison_matcher!(
+    /// Matches values less than the expected one, same as [`assert_lt!`] does.
+    ///
+    /// [`assert_lt!`]: ../macro.assert_lt.html
+    Lt, lt, PartialOrd, <, "less than"
+);
+
+comparison_matcher!(
+    /// Matches values less or equal than the expected one, same as [`assert_le!`] does.
+    ///
+    /// [`assert_le!`]: ../macro.assert_le.html
+    Le, le, PartialOrd, <=, "less or equal than"
+);
@@ -0,0 +1,142 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::Matcher;
+
+/// Matches collections with at least one element accepted by the inner matcher.
+///
+/// See [`contains`].
+///
+/// [`contains`]: ./fn.contains.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contains<M>(M);
+
+/// Returns a matcher, which accepts collections with at least one element
+/// accepted by the `matcher`.
+///
+/// Any collection, which reference can be iterated over, is supported, ex. `Vec<T>` or `[T; N]`.
+/// Slice references should be dereferenced first.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use claim::matchers::{contains, eq, gt};
+/// # fn main() {
+///
+/// let values = vec![1, 5, 7];
+/// assert_that!(values, contains(eq(5)));
+/// assert_that!(values[..], contains(gt(6)));
+/// # }
+/// ```
+pub fn contains<M>(matcher: M) -> Contains<M> {
+    Contains(matcher)
+}
+
+impl<C: ?Sized, E, M> Matcher<C> for Contains<M>
+where
+    for<'a> &'a C: IntoIterator<Item = &'a E>,
+    M: Matcher<E>,
+{
+    fn matches(&self, actual: &C) -> bool {
+        actual.into_iter().any(|item| self.0.matches(item))
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("containing an element ")?;
+        self.0.describe(f)
+    }
+
+    fn describe_mismatch(&self, _actual: &C, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("had no element ")?;
+        self.0.describe(f)
+    }
+}
+
+/// Values with a length, which can be checked with the [`len`] matcher.
+///
+/// [`len`]: ./fn.len.html
+pub trait Length {
+    /// Returns the length of this value.
+    fn length(&self) -> usize;
+}
+
+impl Length for str {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for [T] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Length for String {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Length for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T: ?Sized + Length> Length for &T {
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+/// Matches values of the exact length.
+///
+/// See [`len`].
+///
+/// [`len`]: ./fn.len.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Len(usize);
+
+/// Returns a matcher, which accepts values with the `expected` [length].
+///
+/// String lengths are measured in bytes, same as [`str::len`] does.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use claim::matchers::len;
+/// # fn main() {
+///
+/// assert_that!("abcde", len(5));
+/// assert_that!([1, 2, 3][..], len(3));
+/// # }
+/// ```
+///
+/// [length]: ./trait.Length.html
+/// [`str::len`]: https://doc.rust-lang.org/core/primitive.str.html#method.len
+pub fn len(expected: usize) -> Len {
+    Len(expected)
+}
+
+impl<T: ?Sized + Length> Matcher<T> for Len {
+    fn matches(&self, actual: &T) -> bool {
+        actual.length() == self.0
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "with length {}", self.0)
+    }
+
+    fn describe_mismatch(&self, actual: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "had length {}", actual.length())
+    }
+}
@@ -0,0 +1,147 @@
+use core::fmt;
+
+use super::Matcher;
+
+/// Matches values accepted by both inner matchers.
+///
+/// See [`and`] and [`all_of!`].
+///
+/// [`and`]: ./fn.and.html
+/// [`all_of!`]: ../macro.all_of.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct And<A, B>(A, B);
+
+/// Returns a matcher, which accepts values accepted by both `left` and `right` matchers.
+pub fn and<A, B>(left: A, right: B) -> And<A, B> {
+    And(left, right)
+}
+
+impl<T: ?Sized, A: Matcher<T>, B: Matcher<T>> Matcher<T> for And<A, B> {
+    fn matches(&self, actual: &T) -> bool {
+        self.0.matches(actual) && self.1.matches(actual)
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.describe(f)?;
+        f.write_str(" and ")?;
+        self.1.describe(f)
+    }
+
+    fn describe_mismatch(&self, actual: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.matches(actual) {
+            self.1.describe_mismatch(actual, f)
+        } else {
+            self.0.describe_mismatch(actual, f)
+        }
+    }
+}
+
+/// Matches values accepted by any of inner matchers.
+///
+/// See [`or`] and [`any_of!`].
+///
+/// [`or`]: ./fn.or.html
+/// [`any_of!`]: ../macro.any_of.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Or<A, B>(A, B);
+
+/// Returns a matcher, which accepts values accepted by either `left` or `right` matcher.
+pub fn or<A, B>(left: A, right: B) -> Or<A, B> {
+    Or(left, right)
+}
+
+impl<T: ?Sized, A: Matcher<T>, B: Matcher<T>> Matcher<T> for Or<A, B> {
+    fn matches(&self, actual: &T) -> bool {
+        self.0.matches(actual) || self.1.matches(actual)
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.describe(f)?;
+        f.write_str(" or ")?;
+        self.1.describe(f)
+    }
+
+    fn describe_mismatch(&self, actual: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.describe_mismatch(actual, f)?;
+        f.write_str(" and ")?;
+        self.1.describe_mismatch(actual, f)
+    }
+}
+
+/// Matches values rejected by the inner matcher.
+///
+/// See [`not`].
+///
+/// [`not`]: ./fn.not.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Not<M>(M);
+
+/// Returns a matcher, which accepts values rejected by the `matcher`.
+pub fn not<M>(matcher: M) -> Not<M> {
+    Not(matcher)
+}
+
+impl<T: ?Sized, M: Matcher<T>> Matcher<T> for Not<M> {
+    fn matches(&self, actual: &T) -> bool {
+        !self.0.matches(actual)
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("not (")?;
+        self.0.describe(f)?;
+        f.write_str(")")
+    }
+
+    fn describe_mismatch(&self, _actual: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("was ")?;
+        self.0.describe(f)
+    }
+}
+
+/// Creates a matcher, which accepts values accepted by all of the given matchers.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use claim::matchers::{gt, lt, ne};
+/// # fn main() {
+///
+/// assert_that!(5, all_of![gt(3), lt(10), ne(7)]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! all_of {
+    ($matcher:expr $(,)*) => {
+        $matcher
+    };
+    ($matcher:expr, $($rest:expr),+ $(,)*) => {
+        $crate::matchers::and($matcher, $crate::all_of!($($rest),+))
+    };
+}
+
+/// Creates a matcher, which accepts values accepted by any of the given matchers.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use claim::matchers::{eq, gt};
+/// # fn main() {
+///
+/// assert_that!(0, any_of![eq(0), gt(10)]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! any_of {
+    ($matcher:expr $(,)*) => {
+        $matcher
+    };
+    ($matcher:expr, $($rest:expr),+ $(,)*) => {
+        $crate::matchers::or($matcher, $crate::any_of!($($rest),+))
+    };
+}
@@ -0,0 +1,191 @@
+//! Composable matchers for the [`assert_that!`] macro.
+//!
+//! This module is available for Rust 1.31+.
+//!
+//! A [`Matcher`] describes a set of acceptable values, similar to the Hamcrest library.
+//! Matchers can be combined with [`all_of!`], [`any_of!`], [`not`]
+//! or with the `and` / `or` methods of the built-in matchers.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! # #[macro_use] extern crate claim;
+//! use claim::matchers::{contains, eq, gt, len, lt, starts_with};
+//! # fn main() {
+//!
+//! assert_that!(5, all_of![gt(3), lt(10)]);
+//! assert_that!(vec![1, 5, 7], contains(eq(5)));
+//! assert_that!("abcde", starts_with("ab").and(len(5)));
+//! # }
+//! ```
+//!
+//! [`assert_that!`]: ../macro.assert_that.html
+//! [`all_of!`]: ../macro.all_of.html
+//! [`any_of!`]: ../macro.any_of.html
+//! [`Matcher`]: ./trait.Matcher.html
+//! [`not`]: ./fn.not.html
+
+use core::fmt;
+use core::marker::PhantomData;
+
+mod cmp;
+mod collections;
+#[macro_use]
+mod logic;
+mod strings;
+
+pub use self::cmp::{eq, ge, gt, le, lt, ne, Eq, Ge, Gt, Le, Lt, Ne};
+pub use self::collections::{contains, len, Contains, Len, Length};
+pub use self::logic::{and, not, or, And, Not, Or};
+pub use self::strings::{ends_with, starts_with, EndsWith, StartsWith};
+
+/// A predicate over values of `T` type, which is able to explain itself.
+///
+/// Built-in matchers use the same comparison semantics as the other macros of this crate,
+/// ex. [`gt`] matcher is satisfied in the same cases as [`assert_gt!`] does not panic.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use std::fmt;
+///
+/// use claim::matchers::Matcher;
+///
+/// struct Even;
+///
+/// impl Matcher<i32> for Even {
+///     fn matches(&self, actual: &i32) -> bool {
+///         actual % 2 == 0
+///     }
+///
+///     fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str("an even number")
+///     }
+/// }
+///
+/// # fn main() {
+/// assert_that!(42, Even);
+/// # }
+/// ```
+///
+/// [`gt`]: ./fn.gt.html
+/// [`assert_gt!`]: ../macro.assert_gt.html
+pub trait Matcher<T: ?Sized> {
+    /// Returns `true` if `actual` value is accepted by this matcher.
+    fn matches(&self, actual: &T) -> bool;
+
+    /// Writes the description of the accepted values, ex. `greater than 3`.
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Writes the explanation why `actual` value was not accepted.
+    ///
+    /// Default implementation writes `was not ` followed by the matcher [description].
+    ///
+    /// [description]: #tymethod.describe
+    fn describe_mismatch(&self, actual: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let _ = actual;
+        f.write_str("was not ")?;
+        self.describe(f)
+    }
+}
+
+/// [`Display`] adapter for the [`Matcher::describe`] method.
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+/// [`Matcher::describe`]: ./trait.Matcher.html#tymethod.describe
+pub struct Description<'a, T: ?Sized, M> {
+    matcher: &'a M,
+    actual: PhantomData<fn(&T)>,
+}
+
+/// Returns the [`Display`] adapter for the matcher description.
+///
+/// `actual` value is only used to select the [`Matcher<T>`] implementation.
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+/// [`Matcher<T>`]: ./trait.Matcher.html
+pub fn description<'a, T: ?Sized, M: Matcher<T>>(
+    matcher: &'a M,
+    _actual: &T,
+) -> Description<'a, T, M> {
+    Description {
+        matcher,
+        actual: PhantomData,
+    }
+}
+
+impl<'a, T: ?Sized, M: Matcher<T>> fmt::Display for Description<'a, T, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.matcher.describe(f)
+    }
+}
+
+impl<'a, T: ?Sized, M: Matcher<T>> fmt::Debug for Description<'a, T, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.matcher.describe(f)
+    }
+}
+
+/// [`Display`] adapter for the [`Matcher::describe_mismatch`] method.
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+/// [`Matcher::describe_mismatch`]: ./trait.Matcher.html#method.describe_mismatch
+pub struct Mismatch<'a, T: ?Sized, M> {
+    matcher: &'a M,
+    actual: &'a T,
+}
+
+/// Returns the [`Display`] adapter for the explanation why `actual` value was not accepted.
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+pub fn mismatch<'a, T: ?Sized, M: Matcher<T>>(matcher: &'a M, actual: &'a T) -> Mismatch<'a, T, M> {
+    Mismatch { matcher, actual }
+}
+
+impl<'a, T: ?Sized, M: Matcher<T>> fmt::Display for Mismatch<'a, T, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.matcher.describe_mismatch(self.actual, f)
+    }
+}
+
+impl<'a, T: ?Sized, M: Matcher<T>> fmt::Debug for Mismatch<'a, T, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.matcher.describe_mismatch(self.actual, f)
+    }
+}
+
+/// Implements `and` and `or` combinator methods for the built-in matchers.
+macro_rules! impl_combinators {
+    ($($name:ident<$($param:ident),*>),+ $(,)*) => {
+        $(
+            impl<$($param),*> $name<$($param),*> {
+                /// Returns a matcher, which accepts values accepted by both `self` and `other`.
+                pub fn and<R>(self, other: R) -> $crate::matchers::And<Self, R> {
+                    $crate::matchers::and(self, other)
+                }
+
+                /// Returns a matcher, which accepts values accepted by either `self` or `other`.
+                pub fn or<R>(self, other: R) -> $crate::matchers::Or<Self, R> {
+                    $crate::matchers::or(self, other)
+                }
+            }
+        )+
+    };
+}
+
+impl_combinators!(
+    Eq<U>,
+    Ne<U>,
+    Gt<U>,
+    Ge<U>,
+    Lt<U>,
+    Le<U>,
+    And<A, B>,
+    Or<A, B>,
+    Not<M>,
+    Contains<M>,
+    Len<>,
+    StartsWith<P>,
+    EndsWith<P>,
+);
@@ -0,0 +1,72 @@
+use core::fmt;
+
+use super::Matcher;
+
+/// Matches strings starting with the expected prefix.
+///
+/// See [`starts_with`].
+///
+/// [`starts_with`]: ./fn.starts_with.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StartsWith<P>(P);
+
+/// Returns a matcher, which accepts strings starting with the `prefix`.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use claim::matchers::starts_with;
+/// # fn main() {
+///
+/// assert_that!("abcde", starts_with("ab"));
+/// assert_that!(String::from("abcde"), starts_with("abc"));
+/// # }
+/// ```
+pub fn starts_with<P: AsRef<str>>(prefix: P) -> StartsWith<P> {
+    StartsWith(prefix)
+}
+
+impl<T: ?Sized + AsRef<str>, P: AsRef<str>> Matcher<T> for StartsWith<P> {
+    fn matches(&self, actual: &T) -> bool {
+        actual.as_ref().starts_with(self.0.as_ref())
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "starting with {:?}", self.0.as_ref())
+    }
+}
+
+/// Matches strings ending with the expected suffix.
+///
+/// See [`ends_with`].
+///
+/// [`ends_with`]: ./fn.ends_with.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EndsWith<P>(P);
+
+/// Returns a matcher, which accepts strings ending with the `suffix`.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claim;
+/// use claim::matchers::ends_with;
+/// # fn main() {
+///
+/// assert_that!("abcde", ends_with("de"));
+/// # }
+/// ```
+pub fn ends_with<P: AsRef<str>>(suffix: P) -> EndsWith<P> {
+    EndsWith(suffix)
+}
+
+impl<T: ?Sized + AsRef<str>, P: AsRef<str>> Matcher<T> for EndsWith<P> {
+    fn matches(&self, actual: &T) -> bool {
+        actual.as_ref().ends_with(self.0.as_ref())
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ending with {:?}", self.0.as_ref())
+    }
+}